@@ -2,8 +2,45 @@ use std::fmt;
 
 use krabmaga::engine::{agent::Agent, state::State};
 
+use crate::config::{FiscalRuleMode, TaxSchedule};
 use crate::state::EconomyState;
 
+// ─────────────────────────────────────────────────────────────────────────────
+// Loan book
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// A single outstanding loan from a bank to a firm.
+///
+/// Replaces the scalar `loans` balance with per-loan tracking so that
+/// amortization, maturity, and collateral recovery can be modelled
+/// individually rather than against an aggregate pool.
+#[derive(Clone, Debug)]
+pub struct Loan {
+    pub borrower_id: usize,
+    pub principal: f64,
+    /// Rate fixed at origination; does not change if the bank's posted
+    /// lending rate moves later.
+    pub rate: f64,
+    pub remaining_term: u32,
+    pub collateral_value: f64,
+}
+
+impl Loan {
+    /// Risk multiplier applied to this loan's principal when computing
+    /// risk-weighted assets. Thinner collateral coverage and longer
+    /// remaining maturities both raise the effective weight.
+    fn risk_multiplier(&self) -> f64 {
+        let coverage = if self.principal > 0.0 {
+            self.collateral_value / self.principal
+        } else {
+            1.0
+        };
+        let coverage_adj = (1.5 - coverage.min(1.5)).max(0.5);
+        let maturity_adj = 1.0 + (self.remaining_term as f64 / 40.0).min(0.5);
+        coverage_adj * maturity_adj
+    }
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Bank data
 // ─────────────────────────────────────────────────────────────────────────────
@@ -13,14 +50,29 @@ use crate::state::EconomyState;
 pub struct BankData {
     pub capital: f64,
     pub reserves: f64,
+    /// Cached sum of `loan_book` principal balances, kept in sync by
+    /// `amortize_loan_book`, `extend_loan`, and `liquidate_borrower_loans`.
     pub loans: f64,
     pub deposits: f64,
+    /// Principal written off to default this period; used as a risk-premium
+    /// input in `set_lending_rate`, then zeroed by `step` once consumed so
+    /// `npl_ratio` reflects this period's defaults rather than an
+    /// ever-growing lifetime total.
     pub non_performing_loans: f64,
     pub interest_rate: f64,
     pub profit: f64,
     // Internal per-period income calculations
     pub interest_income: f64,
     pub interest_expense: f64,
+    /// Interest accrued on the loan book this period (sum of
+    /// `principal * rate` across loans), computed by `amortize_loan_book`.
+    pub loan_interest_accrued: f64,
+    /// Individual loans outstanding to firms.
+    pub loan_book: Vec<Loan>,
+    // Interbank market position (see `crate::markets::interbank`)
+    pub interbank_assets: f64,
+    pub interbank_liabilities: f64,
+    pub interbank_rate: f64,
 }
 
 impl BankData {
@@ -35,13 +87,25 @@ impl BankData {
             profit: 0.0,
             interest_income: 0.0,
             interest_expense: 0.0,
+            loan_interest_accrued: 0.0,
+            loan_book: Vec::new(),
+            interbank_assets: 0.0,
+            interbank_liabilities: 0.0,
+            interbank_rate: 0.0,
         }
     }
 
     // ─── Regulatory ratios ──────────────────────────────────────────────────
 
+    pub(crate) fn risk_weighted_assets(&self, base_risk_weight: f64) -> f64 {
+        self.loan_book
+            .iter()
+            .map(|loan| loan.principal * base_risk_weight * loan.risk_multiplier())
+            .sum()
+    }
+
     pub fn capital_ratio(&self, risk_weight: f64) -> f64 {
-        let risk_weighted = self.loans * risk_weight;
+        let risk_weighted = self.risk_weighted_assets(risk_weight);
         if risk_weighted <= 0.0 {
             1.0
         } else {
@@ -69,22 +133,58 @@ impl BankData {
         self.interest_rate = policy_rate + base_markup + risk * npl_ratio;
     }
 
+    /// Accrue interest on the loan book and amortize principal on each
+    /// loan's fixed schedule, dropping loans that have matured or fully
+    /// amortized. Updates `loans` and `deposits` (principal repayments
+    /// extinguish the deposit money created at origination) and returns
+    /// each `(borrower_id, installment)` paid this period so the caller can
+    /// retire the matching liability on the borrower's own books.
+    pub fn amortize_loan_book(&mut self) -> Vec<(usize, f64)> {
+        let mut interest_accrued = 0.0;
+        let mut principal_repaid = 0.0;
+        let mut repayments = Vec::new();
+        for loan in self.loan_book.iter_mut() {
+            interest_accrued += loan.principal * loan.rate;
+            if loan.remaining_term > 0 {
+                let installment = loan.principal / loan.remaining_term as f64;
+                loan.principal -= installment;
+                loan.remaining_term -= 1;
+                principal_repaid += installment;
+                repayments.push((loan.borrower_id, installment));
+            }
+        }
+        self.loan_book
+            .retain(|loan| loan.remaining_term > 0 && loan.principal > 1e-6);
+        self.loans = self.loan_book.iter().map(|loan| loan.principal).sum();
+        self.deposits = (self.deposits - principal_repaid).max(0.0);
+        self.loan_interest_accrued = interest_accrued;
+        repayments
+    }
+
     pub fn calculate_income(&mut self) {
-        self.interest_income = self.interest_rate * self.loans;
+        self.interest_income =
+            self.loan_interest_accrued + self.interbank_rate * self.interbank_assets;
         let deposit_rate = (self.interest_rate - 0.02).max(0.0);
-        self.interest_expense = deposit_rate * self.deposits;
+        self.interest_expense =
+            deposit_rate * self.deposits + self.interbank_rate * self.interbank_liabilities;
     }
 
     pub fn update_capital(&mut self) {
-        let provisions = self.non_performing_loans * 0.5;
-        self.profit = self.interest_income - self.interest_expense - provisions;
+        self.profit = self.interest_income - self.interest_expense;
         self.capital += self.profit;
     }
 
-    pub fn step(&mut self, policy_rate: f64, base_markup: f64, risk: f64) {
+    pub fn step(&mut self, policy_rate: f64, base_markup: f64, risk: f64) -> Vec<(usize, f64)> {
         self.set_lending_rate(policy_rate, base_markup, risk);
+        // `non_performing_loans` has now fed this period's risk premium;
+        // clear it so next period's defaults (booked by
+        // `liquidate_borrower_loans` before this runs) don't pile onto a
+        // growing lifetime total.
+        self.non_performing_loans = 0.0;
+        let repayments = self.amortize_loan_book();
         self.calculate_income();
         self.update_capital();
+        repayments
     }
 
     // ─── Lending interface ──────────────────────────────────────────────────
@@ -113,18 +213,60 @@ impl BankData {
         debt_service >= lending_threshold
     }
 
-    pub fn extend_loan(&mut self, amount: f64) -> f64 {
+    pub fn extend_loan(
+        &mut self,
+        borrower_id: usize,
+        amount: f64,
+        term: u32,
+        collateral_value: f64,
+    ) -> f64 {
+        self.loan_book.push(Loan {
+            borrower_id,
+            principal: amount,
+            rate: self.interest_rate,
+            remaining_term: term,
+            collateral_value,
+        });
         self.loans += amount;
         self.deposits += amount; // loan creates deposit
         self.interest_rate
     }
 
-    pub fn record_default(&mut self, amount: f64) {
-        self.non_performing_loans += amount;
-    }
-
-    pub fn record_repayment(&mut self, amount: f64) {
-        self.loans = (self.loans - amount).max(0.0);
+    /// Liquidate every loan owed by a bankrupt borrower: collateral is sold
+    /// at `haircut` below book value, the recovered cash is banked as
+    /// reserves, and only the shortfall `(outstanding - recovery).max(0.0)`
+    /// is booked as a loss to capital. Returns the number of loans
+    /// liquidated, their total outstanding principal, the total loss booked,
+    /// and the total collateral recovered (which the caller must debit from
+    /// the borrower's own balance sheet, since it's no longer the firm's
+    /// asset — see `markets::credit::clear_credit_market`).
+    pub fn liquidate_borrower_loans(
+        &mut self,
+        borrower_id: usize,
+        haircut: f64,
+    ) -> (usize, f64, f64, f64) {
+        let book = std::mem::take(&mut self.loan_book);
+        let (defaulted, remaining): (Vec<Loan>, Vec<Loan>) = book
+            .into_iter()
+            .partition(|loan| loan.borrower_id == borrower_id);
+        self.loan_book = remaining;
+
+        let mut loss = 0.0;
+        let mut principal_total = 0.0;
+        let mut recovered_total = 0.0;
+        for loan in &defaulted {
+            let recovery = loan.collateral_value * (1.0 - haircut);
+            let recovered_cash = recovery.min(loan.principal);
+            let shortfall = (loan.principal - recovery).max(0.0);
+            self.capital -= shortfall;
+            self.reserves += recovered_cash;
+            self.non_performing_loans += loan.principal;
+            self.loans -= loan.principal;
+            loss += shortfall;
+            principal_total += loan.principal;
+            recovered_total += recovered_cash;
+        }
+        (defaulted.len(), principal_total, loss, recovered_total)
     }
 }
 
@@ -189,6 +331,9 @@ pub struct GovernmentData {
     pub deficit: f64,
     pub debt: f64,
     pub gdp_estimate: f64,
+    /// Multiplier applied to the income-tax schedule by the debt-stabilising
+    /// fiscal rule (1.0 = schedule applied as configured).
+    pub income_tax_multiplier: f64,
 }
 
 impl GovernmentData {
@@ -200,6 +345,7 @@ impl GovernmentData {
             deficit: 0.0,
             debt: 0.0,
             gdp_estimate: 0.0,
+            income_tax_multiplier: 1.0,
         }
     }
 
@@ -221,6 +367,113 @@ impl GovernmentData {
         tax
     }
 
+    /// Tax owed under a bracketed marginal-rate schedule.
+    ///
+    /// `schedule` is a sorted `(threshold, marginal_rate)` list: each entry
+    /// is the lower bound of a bracket and the rate applied to income within
+    /// it. A single-entry schedule `[(0.0, rate)]` reproduces the flat-rate
+    /// behaviour of [`collect_income_tax`](Self::collect_income_tax).
+    pub fn bracket_tax_liability(income: f64, schedule: &[(f64, f64)]) -> f64 {
+        if income <= 0.0 || schedule.is_empty() {
+            return 0.0;
+        }
+        let mut tax = 0.0;
+        for (i, &(lower, marginal_rate)) in schedule.iter().enumerate() {
+            let upper = schedule
+                .get(i + 1)
+                .map(|&(threshold, _)| threshold)
+                .unwrap_or(f64::INFINITY);
+            let taxable = (income.min(upper) - lower).max(0.0);
+            tax += marginal_rate * taxable;
+        }
+        tax
+    }
+
+    /// Marginal rate implied by a bracketed schedule: the rate of the
+    /// bracket `income` currently falls in.
+    pub fn bracket_marginal_rate(income: f64, schedule: &[(f64, f64)]) -> f64 {
+        schedule
+            .iter()
+            .rev()
+            .find(|&&(lower, _)| income >= lower)
+            .map(|&(_, rate)| rate)
+            .unwrap_or(0.0)
+    }
+
+    /// OG-USA-style saturating effective tax rate: `tau(x) = max_rate * (a*x
+    /// + b) / (a*x + b + c)`. The rate is 0 at `x = 0` (when `b = 0`) and
+    /// asymptotes toward `max_rate` as `x` grows, with `c` controlling how
+    /// quickly it saturates.
+    pub fn hyperbolic_effective_tax_rate(income: f64, a: f64, b: f64, c: f64, max_rate: f64) -> f64 {
+        if income <= 0.0 {
+            return 0.0;
+        }
+        let numerator = a * income + b;
+        max_rate * numerator / (numerator + c).max(1e-9)
+    }
+
+    /// Marginal rate implied by [`hyperbolic_effective_tax_rate`](Self::hyperbolic_effective_tax_rate):
+    /// the analytic derivative of `tau(x) * x` with respect to `x`.
+    pub fn hyperbolic_marginal_tax_rate(income: f64, a: f64, b: f64, c: f64, max_rate: f64) -> f64 {
+        if income <= 0.0 {
+            return 0.0;
+        }
+        let numerator = a * income + b;
+        let denominator = (numerator + c).max(1e-9);
+        let tau = max_rate * numerator / denominator;
+        let tau_prime = max_rate * a * c / (denominator * denominator);
+        tau + tau_prime * income
+    }
+
+    /// Tax liability implied by [`hyperbolic_effective_tax_rate`](Self::hyperbolic_effective_tax_rate): `tau(x) * x`.
+    pub fn hyperbolic_tax_liability(income: f64, a: f64, b: f64, c: f64, max_rate: f64) -> f64 {
+        (Self::hyperbolic_effective_tax_rate(income, a, b, c, max_rate) * income).max(0.0)
+    }
+
+    /// Tax owed on household taxable income under the configured baseline
+    /// `TaxSchedule`, alongside the marginal rate on the next unit of income
+    /// (so downstream labour-supply responses can use it, e.g. via
+    /// `HouseholdData::marginal_tax_rate`).
+    pub fn income_tax_liability(income: f64, schedule: &TaxSchedule) -> (f64, f64) {
+        match schedule {
+            TaxSchedule::Bracket(brackets) => (
+                Self::bracket_tax_liability(income, brackets),
+                Self::bracket_marginal_rate(income, brackets),
+            ),
+            TaxSchedule::Hyperbolic { a, b, c, max_rate } => (
+                Self::hyperbolic_tax_liability(income, *a, *b, *c, *max_rate),
+                Self::hyperbolic_marginal_tax_rate(income, *a, *b, *c, *max_rate),
+            ),
+        }
+    }
+
+    /// Gouveia–Strauss effective tax rate on income `y`:
+    /// `ETR(y) = phi * (1 - (s*y^p + 1)^(-1/p))`, where `phi` is the
+    /// asymptotic top rate, `p` controls curvature, and `s` scales the
+    /// income threshold at which the rate ramps up.
+    pub fn gs_effective_tax_rate(income: f64, phi: f64, curvature: f64, scale: f64) -> f64 {
+        if income <= 0.0 {
+            return 0.0;
+        }
+        phi * (1.0 - (scale * income.powf(curvature) + 1.0).powf(-1.0 / curvature))
+    }
+
+    /// Gouveia–Strauss marginal tax rate: the analytic derivative of
+    /// [`gs_effective_tax_rate`](Self::gs_effective_tax_rate) with respect to `y`.
+    pub fn gs_marginal_tax_rate(income: f64, phi: f64, curvature: f64, scale: f64) -> f64 {
+        if income <= 0.0 {
+            return 0.0;
+        }
+        phi * scale
+            * income.powf(curvature - 1.0)
+            * (scale * income.powf(curvature) + 1.0).powf(-1.0 / curvature - 1.0)
+    }
+
+    /// Tax liability implied by [`gs_effective_tax_rate`](Self::gs_effective_tax_rate): `ETR(y) * y`.
+    pub fn gs_tax_liability(income: f64, phi: f64, curvature: f64, scale: f64) -> f64 {
+        (Self::gs_effective_tax_rate(income, phi, curvature, scale) * income).max(0.0)
+    }
+
     pub fn calculate_spending(&mut self, spending_gdp_ratio: f64) -> f64 {
         self.expenditure = spending_gdp_ratio * self.gdp_estimate.max(0.0);
         self.expenditure
@@ -237,20 +490,98 @@ impl GovernmentData {
         total
     }
 
-    pub fn apply_fiscal_rule(&mut self, deficit_target: f64, speed: f64) {
+    /// Apply the configured fiscal rule.
+    ///
+    /// `FiscalRuleMode::DeficitTarget` (the original behaviour) reins in
+    /// expenditure when the flow deficit drifts from `deficit_target`.
+    /// `FiscalRuleMode::DebtStabilization` instead closes the gap between
+    /// the current debt-to-GDP ratio and `debt_target_ratio` by adjusting
+    /// `income_tax_multiplier`, a simple tax-smoothing rule under
+    /// incomplete markets: taxes rise gradually as debt drifts above the
+    /// target ratio rather than jumping to balance the budget immediately.
+    /// `FiscalRuleMode::DebtClosure` instead trims expenditure directly to
+    /// steer the debt stock toward `debt_ratio_ss * gdp`, active only once
+    /// `period >= fiscal_closure_start_period`. With
+    /// `fiscal_closure_budget_balance` set it instead cuts expenditure by the
+    /// primary deficit (`expenditure + transfer_spending - tax_revenue`);
+    /// the debt service and household interest charged later in
+    /// `end_period` are not covered, and expenditure is not cut below zero,
+    /// so the period's deficit can still be nonzero.
+    ///
+    /// Returns `(debt_target, closure_adjustment)`: the steady-state debt
+    /// level implied by `debt_ratio_ss` and the expenditure cut actually
+    /// applied under `DebtClosure` (zero under the other two modes).
+    #[allow(clippy::too_many_arguments)]
+    pub fn apply_fiscal_rule(
+        &mut self,
+        mode: FiscalRuleMode,
+        deficit_target: f64,
+        speed: f64,
+        debt_target_ratio: f64,
+        debt_stabilization_speed: f64,
+        period: u64,
+        debt_ratio_ss: f64,
+        fiscal_closure_gain: f64,
+        fiscal_closure_start_period: u64,
+        fiscal_closure_budget_balance: bool,
+    ) -> (f64, f64) {
+        let debt_target = debt_ratio_ss * self.gdp_estimate.max(0.0);
         if self.gdp_estimate <= 0.0 {
-            return;
+            return (debt_target, 0.0);
+        }
+        let mut closure_adjustment = 0.0;
+        match mode {
+            FiscalRuleMode::DeficitTarget => {
+                let current_deficit_ratio = self.deficit.abs() / self.gdp_estimate.max(1e-9);
+                let gap = current_deficit_ratio - deficit_target;
+                let adjustment = speed * gap * self.gdp_estimate;
+                self.expenditure = (self.expenditure - adjustment).max(0.0);
+            }
+            FiscalRuleMode::DebtStabilization => {
+                let debt_ratio = self.debt / self.gdp_estimate.max(1e-9);
+                let gap = debt_ratio - debt_target_ratio;
+                self.income_tax_multiplier = (self.income_tax_multiplier
+                    + debt_stabilization_speed * gap)
+                    .clamp(0.1, 3.0);
+            }
+            FiscalRuleMode::DebtClosure => {
+                if period >= fiscal_closure_start_period {
+                    let requested = if fiscal_closure_budget_balance {
+                        (self.expenditure + self.transfer_spending - self.tax_revenue).max(0.0)
+                    } else {
+                        (fiscal_closure_gain * (self.debt - debt_target)).max(0.0)
+                    };
+                    closure_adjustment = requested.min(self.expenditure.max(0.0));
+                    self.expenditure = (self.expenditure - closure_adjustment).max(0.0);
+                }
+            }
         }
-        let current_deficit_ratio =
-            self.deficit.abs() / self.gdp_estimate.max(1e-9);
-        let gap = current_deficit_ratio - deficit_target;
-        let adjustment = speed * gap * self.gdp_estimate;
-        self.expenditure = (self.expenditure - adjustment).max(0.0);
+        (debt_target, closure_adjustment)
     }
 
-    pub fn end_period(&mut self) {
-        self.deficit = self.tax_revenue - (self.expenditure + self.transfer_spending);
+    /// End the period: charge debt service at `r_gov`, fund the interest
+    /// households receive on their wealth (`household_interest_paid`, see
+    /// `HouseholdData::receive_income`'s `capital_income` — the government is
+    /// its real counterparty, since no other sector holds household
+    /// financial assets), and roll the resulting deficit into the debt
+    /// stock, i.e. `debt' = debt + r_gov*debt + household_interest_paid +
+    /// expenditure + transfers - tax_revenue`. Returns the debt service
+    /// charged.
+    ///
+    /// `debt_service` is folded straight into the deficit rather than added
+    /// to `expenditure`: `calculate_spending`/`clear_goods_market` have
+    /// already run for this period by the time `end_period` is called, and
+    /// `expenditure` is re-read afterward as the goods-demand
+    /// `government_spending` row in `accounting::build_flow_matrix` — if
+    /// debt service were mixed in there, that row would book interest
+    /// payments as if they were firm receipts for goods the government never
+    /// actually bought.
+    pub fn end_period(&mut self, r_gov: f64, household_interest_paid: f64) -> f64 {
+        let debt_service = r_gov * self.debt.max(0.0);
+        self.deficit = self.tax_revenue
+            - (self.expenditure + self.transfer_spending + debt_service + household_interest_paid);
         self.debt -= self.deficit; // negative deficit → debt increases
+        debt_service
     }
 }
 
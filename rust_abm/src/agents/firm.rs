@@ -15,6 +15,9 @@ use crate::state::EconomyState;
 #[derive(Clone, Debug)]
 pub struct FirmData {
     pub sector: String,
+    /// Index into `Config::sectors` (and the rows/columns of
+    /// `Config::io_coefficients`) identifying this firm's sector.
+    pub sector_index: usize,
     pub employees: u32,
     pub wage_bill: f64,
     pub turnover: f64,
@@ -33,11 +36,30 @@ pub struct FirmData {
     pub wage_rate: f64,
     pub desired_production: f64,
     pub bankrupt: bool,
+    /// Gross investment spent this period (replacement of depreciation plus
+    /// any capacity expansion); see [`invest`](Self::invest).
+    pub investment: f64,
+    /// This period's spend on intermediate inputs bought from other sectors
+    /// (see [`update_financials`](Self::update_financials)).
+    pub intermediate_cost: f64,
+    /// Desired intermediate-goods purchases by input sector this period
+    /// (`desired_production * io_coefficients[sector_index][input]`); see
+    /// [`plan_production`](Self::plan_production).
+    pub intermediate_demand: Vec<f64>,
+    /// This period's total tax charged (corporate + Gouveia–Strauss capital
+    /// tax; set by `state::run_post_step`), used by
+    /// [`required_cash_buffer`](Self::required_cash_buffer).
+    pub tax_paid: f64,
+    /// Whether this firm's residual net worth has already been swept into
+    /// the bequest pool (see `markets::clear_credit_market`), so a firm that
+    /// stays `bankrupt` across periods isn't collected twice.
+    pub bequest_collected: bool,
 }
 
 impl FirmData {
     pub fn new(
         sector: String,
+        sector_index: usize,
         employees: u32,
         wage_rate: f64,
         turnover: f64,
@@ -48,6 +70,7 @@ impl FirmData {
         let wage_bill = employees as f64 * wage_rate;
         FirmData {
             sector,
+            sector_index,
             employees,
             wage_bill,
             turnover,
@@ -64,15 +87,27 @@ impl FirmData {
             wage_rate,
             desired_production: turnover,
             bankrupt: false,
+            investment: 0.0,
+            intermediate_cost: 0.0,
+            intermediate_demand: Vec::new(),
+            tax_paid: 0.0,
+            bequest_collected: false,
         }
     }
 
     // ─── Step sub-methods ───────────────────────────────────────────────────
 
-    pub fn plan_production(&mut self, inventory_target_ratio: f64) {
+    /// Plan desired output and, from it, the intermediate-goods demand this
+    /// implies on every input sector (`io_row[input] * desired_production`,
+    /// where `io_row` is this firm's row of `Config::io_coefficients`).
+    pub fn plan_production(&mut self, inventory_target_ratio: f64, io_row: &[f64]) {
         let expected_sales = self.turnover / self.price.max(1e-9);
         let desired = expected_sales + inventory_target_ratio * expected_sales - self.inventory;
         self.desired_production = desired.max(0.0);
+        self.intermediate_demand = io_row
+            .iter()
+            .map(|coef| coef * self.desired_production)
+            .collect();
     }
 
     pub fn set_price(&mut self) {
@@ -93,7 +128,11 @@ impl FirmData {
         self.vacancies = desired_employees.saturating_sub(self.employees);
     }
 
-    pub fn produce(&mut self, capacity_utilization_target: f64) {
+    /// `intermediate_availability` is the lagged, demand-weighted average
+    /// fill-ratio (in `[0, 1]`) of this firm's needed input sectors (see
+    /// `GoodsOutcome::sector_fill_ratio`); unfilled intermediate demand caps
+    /// realized output the same way labour and capital capacity already do.
+    pub fn produce(&mut self, capacity_utilization_target: f64, intermediate_availability: f64) {
         let labour_productivity = if self.employees > 0 {
             self.output / (self.employees as f64).max(1.0)
         } else {
@@ -101,29 +140,90 @@ impl FirmData {
         };
         let capacity = self.capital * capacity_utilization_target;
         let labour_output = self.employees as f64 * labour_productivity;
-        self.output = self.desired_production.min(labour_output).min(capacity);
+        let intermediate_cap = self.desired_production * intermediate_availability;
+        self.output = self
+            .desired_production
+            .min(labour_output)
+            .min(capacity)
+            .min(intermediate_cap);
         self.inventory += self.output;
     }
 
-    pub fn update_financials(&mut self, capacity_utilization_target: f64) {
+    /// Settle the period's revenue against wage and intermediate-input costs.
+    ///
+    /// `sector_prices` is each sector's mean price this period (lagged one
+    /// period via `GoodsOutcome::sector_price`, same as every other lagged
+    /// market signal agents read). Only the share of `intermediate_demand`
+    /// actually realized — `output / desired_production`, since unfilled
+    /// intermediate demand already throttled `output` in [`produce`](Self::produce)
+    /// — is paid for. Bankruptcy is no longer decided here — see
+    /// [`required_cash_buffer`](Self::required_cash_buffer).
+    pub fn update_financials(&mut self, sector_prices: &[f64]) {
         let sales_quantity =
             self.inventory.min(self.turnover / self.price.max(1e-9));
         let revenue = sales_quantity * self.price;
         self.inventory = (self.inventory - sales_quantity).max(0.0);
         self.turnover = revenue;
         self.wage_bill = self.employees as f64 * self.wage_rate;
-        self.profit = revenue - self.wage_bill;
+
+        let output_ratio = if self.desired_production > 0.0 {
+            self.output / self.desired_production
+        } else {
+            0.0
+        };
+        self.intermediate_cost = self
+            .intermediate_demand
+            .iter()
+            .zip(sector_prices.iter())
+            .map(|(demand, price)| demand * output_ratio * price)
+            .sum();
+
+        self.profit = revenue - self.wage_bill - self.intermediate_cost;
         self.cash += self.profit;
         self.equity += self.profit;
+    }
 
-        // Bankruptcy check
-        if self.equity < 0.0 && self.capital > 0.0 {
-            let ratio = self.equity / self.capital;
-            let threshold = -capacity_utilization_target;
-            if ratio < threshold {
-                self.bankrupt = true;
-            }
-        }
+    /// Required end-of-period cash buffer: a configurable number of months
+    /// of outflows (wages + intermediate purchases + taxes), interpolated
+    /// between `buffer_months_max` for small firms and `buffer_months_min`
+    /// for large ones as turnover grows — smaller firms need a thicker
+    /// buffer, with the required number of months decaying exponentially in
+    /// turnover toward `buffer_months_min`.
+    pub fn required_cash_buffer(
+        &self,
+        buffer_months_min: f64,
+        buffer_months_max: f64,
+        buffer_size_scale: f64,
+    ) -> f64 {
+        let decay = (-self.turnover / buffer_size_scale).exp();
+        let buffer_months = buffer_months_min + (buffer_months_max - buffer_months_min) * decay;
+        let monthly_outflow = (self.wage_bill + self.intermediate_cost + self.tax_paid) / 3.0;
+        buffer_months * monthly_outflow
+    }
+
+    /// Capital law of motion: `capital' = (1 - delta) * capital + investment`.
+    ///
+    /// Investment always covers replacement of depreciated capital
+    /// (`delta * capital`) plus an expansion component that closes a
+    /// fraction `investment_sensitivity` of the gap between the capital
+    /// stock implied by this period's output (set by
+    /// [`produce`](Self::produce), which runs first) at the target capacity
+    /// utilization and the firm's current capital. Spent out of cash, so a
+    /// firm without the cash on hand borrows the shortfall in the credit
+    /// market exactly as it would for any other negative cash balance.
+    /// `self.investment` is read back by `clear_goods_market`, which routes
+    /// the economy-wide total to `Config::capital_goods_sector_index` as
+    /// demand, so this spend lands as turnover for capital-goods firms
+    /// rather than leaving the model uncounted.
+    pub fn invest(&mut self, delta: f64, capacity_utilization_target: f64, investment_sensitivity: f64) {
+        let depreciation = delta * self.capital;
+        let desired_capital = self.output / capacity_utilization_target.max(1e-9);
+        let expansion = (investment_sensitivity * (desired_capital - self.capital)).max(0.0);
+        let investment = depreciation + expansion;
+
+        self.cash -= investment;
+        self.capital = ((1.0 - delta) * self.capital + investment).max(0.0);
+        self.investment = investment;
     }
 
     // ─── Market interfaces ──────────────────────────────────────────────────
@@ -168,6 +268,47 @@ impl fmt::Display for FirmAgent {
     }
 }
 
+/// The "independent" phase of one firm's period: planning, pricing,
+/// producing, and settling financials using only this firm's own data plus
+/// shared, already-computed inputs (this firm's IO row, lagged per-sector
+/// fill ratios/prices, and config). Touches nothing outside `firm`, so it's
+/// safe to run across every firm in parallel (see
+/// `EconomyState::step_firms_parallel`) as well as serially from
+/// `FirmAgent::step`.
+pub fn step_firm_independent(
+    firm: &mut FirmData,
+    io_row: &[f64],
+    sector_fill_ratio: &[f64],
+    sector_prices: &[f64],
+    inv_ratio: f64,
+    cap_util: f64,
+    delta: f64,
+    investment_sensitivity: f64,
+) {
+    if firm.bankrupt {
+        return;
+    }
+
+    let total_coef: f64 = io_row.iter().sum();
+    let intermediate_availability = if total_coef > 0.0 {
+        io_row
+            .iter()
+            .zip(sector_fill_ratio.iter())
+            .map(|(coef, fill)| coef * fill)
+            .sum::<f64>()
+            / total_coef
+    } else {
+        1.0
+    };
+
+    firm.plan_production(inv_ratio, io_row);
+    firm.set_price();
+    firm.determine_labour_demand();
+    firm.produce(cap_util, intermediate_availability);
+    firm.update_financials(sector_prices);
+    firm.invest(delta, cap_util, investment_sensitivity);
+}
+
 impl Agent for FirmAgent {
     /// Execute one period of firm behaviour, mirroring Python `Firm.step()`.
     fn step(&mut self, state: &mut dyn State) {
@@ -176,19 +317,43 @@ impl Agent for FirmAgent {
             .downcast_mut::<EconomyState>()
             .expect("state should be EconomyState");
 
+        #[cfg(feature = "parallel")]
+        if state.config.parallel_agent_stepping {
+            // Only the firm proxy with id 0 triggers the batch pass;
+            // every other `FirmAgent::step` this period is then a no-op,
+            // since `step_firms_parallel` already covers every firm in one
+            // rayon sweep.
+            if self.id == 0 {
+                state.step_firms_parallel();
+            }
+            return;
+        }
+
         // Extract config values to avoid simultaneous borrows
         let inv_ratio = state.config.inventory_target_ratio;
         let cap_util = state.config.capacity_utilization_target;
+        let delta = state.config.capital_depreciation_rate;
+        let investment_sensitivity = state.config.investment_sensitivity;
 
-        let firm = &mut state.firms[self.id];
-        if firm.bankrupt {
+        if state.firms[self.id].bankrupt {
             return;
         }
 
-        firm.plan_production(inv_ratio);
-        firm.set_price();
-        firm.determine_labour_demand();
-        firm.produce(cap_util);
-        firm.update_financials(cap_util);
+        // This firm's row of the IO matrix: how much of each input sector it
+        // needs per unit of its own output.
+        let io_row = state.config.io_coefficients[state.firms[self.id].sector_index].clone();
+        let sector_fill_ratio = state.goods_last.sector_fill_ratio.clone();
+        let sector_prices = state.goods_last.sector_price.clone();
+
+        step_firm_independent(
+            &mut state.firms[self.id],
+            &io_row,
+            &sector_fill_ratio,
+            &sector_prices,
+            inv_ratio,
+            cap_util,
+            delta,
+            investment_sensitivity,
+        );
     }
 }
@@ -1,6 +1,7 @@
 use std::fmt;
 
 use krabmaga::engine::{agent::Agent, state::State};
+use rand::Rng;
 
 use crate::state::EconomyState;
 
@@ -12,54 +13,144 @@ use crate::state::EconomyState;
 #[derive(Clone, Debug)]
 pub struct HouseholdData {
     pub income: f64,
+    /// Wage income earned this period (before the Gouveia–Strauss labor tax
+    /// applied in `run_post_step`).
+    pub wage_income: f64,
+    /// Interest income earned on `wealth` this period (before the
+    /// Gouveia–Strauss capital tax applied in `run_post_step`); negative
+    /// when the household is a net borrower.
+    pub capital_income: f64,
     pub wealth: f64,
     pub consumption: f64,
     pub savings: f64,
+    /// Realized marginal propensity to consume: the local slope of the
+    /// EGM consumption policy `c(a, y)` at the household's current assets
+    /// (see [`consume`](Self::consume)).
     pub mpc: f64,
+    /// Estimate of permanent income used to size the borrowing limit (see
+    /// [`save`](Self::save)).
+    pub permanent_income: f64,
     pub employed: bool,
     pub employer_id: Option<usize>,
     pub wage: f64,
     pub transfer_income: f64,
+    /// Index into the shared Tauchen-discretized productivity grid
+    /// (`EconomyState::productivity_grid`); evolves as an idiosyncratic
+    /// AR(1) Markov chain (see [`tauchen`]).
+    pub productivity_state: usize,
+    /// Marginal rate on the next unit of income this household faces this
+    /// period: the baseline `Config::income_tax_schedule` marginal rate
+    /// (scaled by `GovernmentData::income_tax_multiplier`) plus the
+    /// Gouveia–Strauss labor-tax marginal rate, set by `state::run_post_step`.
+    /// Exposed for downstream labour-supply responses, not consumed internally.
+    pub marginal_tax_rate: f64,
 }
 
 impl HouseholdData {
-    pub fn new(income: f64, wealth: f64, mpc: f64) -> Self {
+    pub fn new(
+        income: f64,
+        wealth: f64,
+        initial_mpc: f64,
+        permanent_income: f64,
+        productivity_state: usize,
+    ) -> Self {
         HouseholdData {
             income,
+            wage_income: 0.0,
+            capital_income: 0.0,
             wealth,
             consumption: 0.0,
             savings: 0.0,
-            mpc,
+            mpc: initial_mpc,
+            permanent_income: permanent_income.max(1e-6),
             employed: false,
             employer_id: None,
             wage: 0.0,
             transfer_income: 0.0,
+            productivity_state,
+            marginal_tax_rate: 0.0,
         }
     }
 
     // ─── Step sub-methods ───────────────────────────────────────────────────
 
-    fn receive_income(&mut self) {
-        let wage_income = if self.employed { self.wage } else { 0.0 };
-        self.income = wage_income + self.transfer_income;
+    /// Wage and interest income for the period.
+    ///
+    /// Wages scale with the household's current idiosyncratic productivity
+    /// draw; `wealth` (the Bewley–Aiyagari asset balance `a`, which may be
+    /// negative up to the borrowing limit) earns or costs interest at `r`.
+    fn receive_income(&mut self, r: f64, productivity: f64) {
+        let wage_income = if self.employed {
+            self.wage * productivity
+        } else {
+            0.0
+        };
+        let capital_income = r * self.wealth;
+        self.wage_income = wage_income;
+        self.capital_income = capital_income;
+        self.income = wage_income + self.transfer_income + capital_income;
     }
 
-    fn consume(&mut self, smoothing: f64) {
-        let c_income = self.mpc * self.income;
-        let c_wealth = (1.0 - smoothing) * 0.04 * self.wealth;
-        let desired = c_income + c_wealth;
-        self.consumption = desired.max(0.0).min(self.income + self.wealth);
+    /// Endogenous-grid consumption-savings policy lookup.
+    ///
+    /// `asset_grid`/`consumption_policy` come from
+    /// [`solve_egm_policy`], solved once per period (see
+    /// `EconomyState::run_pre_step`) over the shared idiosyncratic
+    /// productivity Markov chain. Looks up `c(a, y)` at this household's
+    /// beginning-of-period assets `a = self.wealth` and productivity state
+    /// `y = self.productivity_state` by linear interpolation, clamped to
+    /// cash-on-hand (`wealth + income`, which can include transfers and
+    /// idiosyncratic wage income the shared policy doesn't see). `self.mpc`
+    /// is exposed as the local slope of the looked-up policy around `a`.
+    fn consume(&mut self, asset_grid: &[f64], consumption_policy: &[Vec<f64>]) {
+        let resources = (self.wealth + self.income).max(0.0);
+        let row = &consumption_policy[self.productivity_state];
+        let (c, slope) = interpolate_with_slope(asset_grid, row, self.wealth);
+
+        self.consumption = c.max(0.0).min(resources);
+        self.mpc = slope.clamp(0.0, 1.0);
     }
 
-    fn save(&mut self) {
+    /// Carry the period's savings into next period's asset balance, clamped
+    /// at the borrowing limit `a >= -borrowing_limit_ratio * permanent_income`.
+    fn save(&mut self, borrowing_limit_ratio: f64) {
         self.savings = self.income - self.consumption;
         self.wealth += self.savings;
+        let borrowing_limit = -borrowing_limit_ratio * self.permanent_income;
+        self.wealth = self.wealth.max(borrowing_limit);
+    }
+
+    /// Draw the next idiosyncratic productivity state from the shared Markov
+    /// transition matrix using a single uniform draw `u ~ [0, 1)`.
+    fn transition_productivity(&mut self, transition: &[Vec<f64>], u: f64) {
+        let row = &transition[self.productivity_state];
+        let mut cumulative = 0.0;
+        for (j, &prob) in row.iter().enumerate() {
+            cumulative += prob;
+            if u < cumulative {
+                self.productivity_state = j;
+                return;
+            }
+        }
+        self.productivity_state = row.len() - 1;
     }
 
-    pub fn step(&mut self, consumption_smoothing: f64) {
-        self.receive_income();
-        self.consume(consumption_smoothing);
-        self.save();
+    #[allow(clippy::too_many_arguments)]
+    pub fn step(
+        &mut self,
+        r: f64,
+        borrowing_limit_ratio: f64,
+        productivity_grid: &[f64],
+        productivity_transition: &[Vec<f64>],
+        transition_draw: f64,
+        egm_asset_grid: &[f64],
+        egm_consumption_policy: &[Vec<f64>],
+    ) {
+        let productivity = productivity_grid[self.productivity_state];
+        self.receive_income(r, productivity);
+        self.consume(egm_asset_grid, egm_consumption_policy);
+        self.save(borrowing_limit_ratio);
+        self.transition_productivity(productivity_transition, transition_draw);
         // Reset transfers after stepping (mirrors Python model)
         self.transfer_income = 0.0;
     }
@@ -110,8 +201,243 @@ impl Agent for HouseholdAgent {
             .downcast_mut::<EconomyState>()
             .expect("state should be EconomyState");
 
-        let smoothing = state.config.consumption_smoothing;
+        #[cfg(feature = "parallel")]
+        if state.config.parallel_agent_stepping {
+            // Only the household proxy with id 0 triggers the batch
+            // pass; every other `HouseholdAgent::step` this period is then a
+            // no-op, since `step_households_parallel` already covers every
+            // household in one rayon sweep.
+            if self.id == 0 {
+                state.step_households_parallel();
+            }
+            return;
+        }
+
+        let r = state.central_bank.policy_rate;
+        let borrowing_limit_ratio = state.config.borrowing_limit_ratio;
+        let productivity_grid = state.productivity_grid.clone();
+        let productivity_transition = state.productivity_transition.clone();
+        let transition_draw: f64 = state.rng.gen();
+        let egm_asset_grid = state.egm_asset_grid.clone();
+        let egm_consumption_policy = state.egm_consumption_policy.clone();
+
         let hh = &mut state.households[self.id];
-        hh.step(smoothing);
+        hh.step(
+            r,
+            borrowing_limit_ratio,
+            &productivity_grid,
+            &productivity_transition,
+            transition_draw,
+            &egm_asset_grid,
+            &egm_consumption_policy,
+        );
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Idiosyncratic productivity process (Tauchen discretization)
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Discretize the AR(1) log-productivity process `log e' = rho * log e + eps`,
+/// `eps ~ N(0, sigma^2)`, via Tauchen's method.
+///
+/// Places `n` equally spaced grid points spanning `± m * sigma / sqrt(1 -
+/// rho^2)` (the process's unconditional standard deviation) and sets the
+/// transition probability from node `i` to node `j` by integrating the
+/// conditional normal density over the cell around `j`, using the two outer
+/// cells to catch the tails. Returns `(log_grid, transition)`, where
+/// `transition[i][j]` is the probability of moving from node `i` to node `j`.
+pub fn tauchen(rho: f64, sigma: f64, n: usize, m: f64) -> (Vec<f64>, Vec<Vec<f64>>) {
+    if n <= 1 {
+        return (vec![0.0; n.max(1)], vec![vec![1.0; n.max(1)]; n.max(1)]);
+    }
+
+    let unconditional_std = sigma / (1.0 - rho * rho).sqrt();
+    let upper = m * unconditional_std;
+    let lower = -upper;
+    let step = (upper - lower) / (n - 1) as f64;
+    let grid: Vec<f64> = (0..n).map(|i| lower + i as f64 * step).collect();
+
+    let transition: Vec<Vec<f64>> = grid
+        .iter()
+        .map(|&e_i| {
+            let mean = rho * e_i;
+            (0..n)
+                .map(|j| {
+                    if j == 0 {
+                        standard_normal_cdf((grid[0] + step / 2.0 - mean) / sigma)
+                    } else if j == n - 1 {
+                        1.0 - standard_normal_cdf((grid[n - 1] - step / 2.0 - mean) / sigma)
+                    } else {
+                        standard_normal_cdf((grid[j] + step / 2.0 - mean) / sigma)
+                            - standard_normal_cdf((grid[j] - step / 2.0 - mean) / sigma)
+                    }
+                })
+                .collect()
+        })
+        .collect();
+
+    (grid, transition)
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Endogenous-grid consumption-savings policy
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Solve the household consumption-savings policy `c(a, y)` via the
+/// endogenous grid method (Carroll 2006), given CRRA marginal utility
+/// `u'(c) = c^{-sigma}`, discount factor `beta`, net interest rate `r`, and a
+/// finite-state labour-income Markov chain `(income_states, transition)` —
+/// in practice the shared Tauchen productivity chain (see [`tauchen`])
+/// scaled by the average wage, reusing the income discretization already fit
+/// from `Config` rather than estimating a second one.
+///
+/// `asset_grid` doubles as both the exogenous end-of-period grid `a'` and
+/// the fixed beginning-of-period grid `a` the policy is reported on, so the
+/// continuation value `c(a', y')` needed for the expectation is a direct
+/// lookup rather than an interpolation. Iterates to convergence (max
+/// absolute change in `c` below `tolerance`) or `max_iterations`, starting
+/// from a "consume all cash-on-hand" guess. Below the lowest endogenous
+/// asset level the borrowing constraint binds and the household consumes
+/// all cash-on-hand down to `borrowing_limit`; above the highest it
+/// extrapolates along the last segment's slope.
+///
+/// Returns `(asset_grid, consumption_policy)`, where
+/// `consumption_policy[y][k]` is consumption at `asset_grid[k]` in income
+/// state `y`.
+#[allow(clippy::too_many_arguments)]
+pub fn solve_egm_policy(
+    income_states: &[f64],
+    transition: &[Vec<f64>],
+    sigma: f64,
+    beta: f64,
+    r: f64,
+    borrowing_limit: f64,
+    asset_max: f64,
+    grid_size: usize,
+    max_iterations: usize,
+    tolerance: f64,
+) -> (Vec<f64>, Vec<Vec<f64>>) {
+    let n_states = income_states.len();
+    let grid_size = grid_size.max(2);
+    let asset_grid: Vec<f64> = (0..grid_size)
+        .map(|k| {
+            borrowing_limit
+                + (asset_max - borrowing_limit) * k as f64 / (grid_size - 1) as f64
+        })
+        .collect();
+
+    let mut policy: Vec<Vec<f64>> = income_states
+        .iter()
+        .map(|&y| {
+            asset_grid
+                .iter()
+                .map(|&a| ((1.0 + r) * a + y).max(1e-9))
+                .collect()
+        })
+        .collect();
+
+    for _ in 0..max_iterations {
+        let mut new_policy = vec![vec![0.0; grid_size]; n_states];
+        let mut max_diff = 0.0f64;
+
+        for y_idx in 0..n_states {
+            let mut endog_assets = Vec::with_capacity(grid_size);
+            let mut endog_c = Vec::with_capacity(grid_size);
+
+            for k in 0..grid_size {
+                let a_prime = asset_grid[k];
+                let rhs: f64 = beta
+                    * (1.0 + r)
+                    * transition[y_idx]
+                        .iter()
+                        .enumerate()
+                        .map(|(y_next, &prob)| prob * policy[y_next][k].powf(-sigma))
+                        .sum::<f64>();
+                let c = rhs.powf(-1.0 / sigma);
+                let a = (c + a_prime - income_states[y_idx]) / (1.0 + r);
+                endog_assets.push(a);
+                endog_c.push(c);
+            }
+
+            for (k, &a) in asset_grid.iter().enumerate() {
+                let c = if a <= endog_assets[0] {
+                    ((1.0 + r) * a + income_states[y_idx] - borrowing_limit).max(1e-9)
+                } else if a >= *endog_assets.last().unwrap() {
+                    let n = endog_assets.len();
+                    let slope = (endog_c[n - 1] - endog_c[n - 2])
+                        / (endog_assets[n - 1] - endog_assets[n - 2]).max(1e-9);
+                    endog_c[n - 1] + slope * (a - endog_assets[n - 1])
+                } else {
+                    interpolate(&endog_assets, &endog_c, a)
+                };
+                new_policy[y_idx][k] = c.max(1e-9);
+                max_diff = max_diff.max((c - policy[y_idx][k]).abs());
+            }
+        }
+
+        policy = new_policy;
+        if max_diff < tolerance {
+            break;
+        }
+    }
+
+    (asset_grid, policy)
+}
+
+/// Linear interpolation of `ys` (sorted by ascending `xs`) at `x`, clamping
+/// to the first/last point rather than extrapolating.
+fn interpolate(xs: &[f64], ys: &[f64], x: f64) -> f64 {
+    if x <= xs[0] {
+        return ys[0];
+    }
+    if x >= xs[xs.len() - 1] {
+        return ys[ys.len() - 1];
+    }
+    let mut hi = 1;
+    while xs[hi] < x {
+        hi += 1;
+    }
+    let lo = hi - 1;
+    let t = (x - xs[lo]) / (xs[hi] - xs[lo]).max(1e-12);
+    ys[lo] + t * (ys[hi] - ys[lo])
+}
+
+/// Linear interpolation of `ys` on grid `xs` at `x`, also returning the local
+/// slope of the bracketing segment (used as the realized MPC). Clamps `x` to
+/// the grid's endpoints, reusing the endpoint segment's slope there.
+fn interpolate_with_slope(xs: &[f64], ys: &[f64], x: f64) -> (f64, f64) {
+    if xs.len() < 2 {
+        return (ys.first().copied().unwrap_or(0.0), 0.0);
     }
+    let clamped = x.clamp(xs[0], xs[xs.len() - 1]);
+    let mut hi = 1;
+    while hi < xs.len() - 1 && xs[hi] < clamped {
+        hi += 1;
+    }
+    let lo = hi - 1;
+    let slope = (ys[hi] - ys[lo]) / (xs[hi] - xs[lo]).max(1e-12);
+    let c = ys[lo] + slope * (clamped - xs[lo]);
+    (c, slope)
+}
+
+/// Standard normal CDF via the Abramowitz & Stegun 7.1.26 `erf` approximation
+/// (max absolute error ~1.5e-7) — accurate enough for Tauchen transition
+/// probabilities without pulling in a statistics crate.
+fn standard_normal_cdf(x: f64) -> f64 {
+    0.5 * (1.0 + erf(x / std::f64::consts::SQRT_2))
+}
+
+fn erf(x: f64) -> f64 {
+    let sign = if x < 0.0 { -1.0 } else { 1.0 };
+    let x = x.abs();
+    let a1 = 0.254829592;
+    let a2 = -0.284496736;
+    let a3 = 1.421413741;
+    let a4 = -1.453152027;
+    let a5 = 1.061405429;
+    let p = 0.3275911;
+    let t = 1.0 / (1.0 + p * x);
+    let y = 1.0 - (((((a5 * t + a4) * t) + a3) * t + a2) * t + a1) * t * (-x * x).exp();
+    sign * y
 }
@@ -3,5 +3,5 @@ pub mod firm;
 pub mod household;
 
 pub use bank::{BankAgent, BankData, CentralBankData, GovernmentData};
-pub use firm::{FirmAgent, FirmData};
-pub use household::{HouseholdAgent, HouseholdData};
+pub use firm::{step_firm_independent, FirmAgent, FirmData};
+pub use household::{solve_egm_policy, tauchen, HouseholdAgent, HouseholdData};
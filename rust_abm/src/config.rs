@@ -1,3 +1,51 @@
+/// Which fiscal rule `GovernmentData::apply_fiscal_rule` enforces.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FiscalRuleMode {
+    /// Rein in expenditure when the flow deficit drifts from `deficit_target`.
+    DeficitTarget,
+    /// Adjust the income-tax multiplier to stabilise debt/GDP at `debt_target_ratio`.
+    DebtStabilization,
+    /// Steer the debt stock toward `debt_ratio_ss * gdp` by trimming
+    /// expenditure, active only from `fiscal_closure_start_period` onward
+    /// (or cut expenditure by the primary deficit when
+    /// `fiscal_closure_budget_balance` is set).
+    DebtClosure,
+}
+
+impl Default for FiscalRuleMode {
+    fn default() -> Self {
+        FiscalRuleMode::DeficitTarget
+    }
+}
+
+/// Which effective-tax-rate function `GovernmentData::income_tax_liability`
+/// applies to household taxable income. This is the baseline schedule only —
+/// the separate Gouveia–Strauss taxes on wage/capital income
+/// (`labor_tax_*`/`capital_tax_*`) are layered on top regardless of which
+/// variant is selected here.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TaxSchedule {
+    /// Piecewise-linear bracket schedule: sorted `(threshold, marginal_rate)`
+    /// pairs, each the lower bound of a bracket and the rate applied to
+    /// income within it. A single entry `[(0.0, rate)]` reproduces a flat rate.
+    Bracket(Vec<(f64, f64)>),
+    /// OG-USA-style saturating effective rate `tau(x) = (a*x + b) / (a*x + b
+    /// + c)`, scaled by `max_rate` so the rate asymptotes toward `max_rate`
+    /// as taxable income `x` grows (see `GovernmentData::hyperbolic_tax_liability`).
+    Hyperbolic {
+        a: f64,
+        b: f64,
+        c: f64,
+        max_rate: f64,
+    },
+}
+
+impl Default for TaxSchedule {
+    fn default() -> Self {
+        TaxSchedule::Bracket(vec![(0.0, 0.20)])
+    }
+}
+
 /// Configuration parameters for the economy simulation.
 ///
 /// Default values match the Python `ModelConfig` defaults.
@@ -8,15 +56,54 @@ pub struct Config {
     pub inventory_target_ratio: f64,
     pub capacity_utilization_target: f64,
     pub markup_adjustment_speed: f64,
+    /// Fraction of the capital stock that depreciates each period.
+    pub capital_depreciation_rate: f64,
+    /// Speed at which firms close the gap between their capital stock and
+    /// the capital implied by recent output at the target capacity
+    /// utilization.
+    pub investment_sensitivity: f64,
 
     // Household behaviour
-    pub consumption_smoothing: f64,
     pub job_search_intensity: f64,
     pub income_mean: f64,
     pub income_std: f64,
     pub wealth_shape: f64,
+    /// Mean of the distribution each household's initial `mpc` (marginal
+    /// propensity to consume, overwritten every period by the EGM policy
+    /// lookup in `HouseholdData::consume`) is drawn from.
     pub mpc_mean: f64,
     pub mpc_std: f64,
+    /// Lower clamp on a household's initial `mpc` draw.
+    pub mpc_min: f64,
+    /// Persistence `rho` of the AR(1) idiosyncratic log-productivity process.
+    pub productivity_persistence: f64,
+    /// Standard deviation `sigma` of the productivity process's innovation.
+    pub productivity_shock_std: f64,
+    /// Number of Tauchen grid points discretizing the productivity process.
+    pub productivity_grid_points: usize,
+    /// Tauchen grid half-width, in multiples of the process's unconditional
+    /// standard deviation.
+    pub productivity_tauchen_width: f64,
+    /// Borrowing limit on household assets, as a multiple of permanent
+    /// income: a household's asset balance cannot fall below
+    /// `-borrowing_limit_ratio * permanent_income`.
+    pub borrowing_limit_ratio: f64,
+    /// CRRA risk-aversion curvature `sigma` in `u'(c) = c^{-sigma}`, used by
+    /// the endogenous-grid consumption-savings policy (see
+    /// `agents::household::solve_egm_policy`).
+    pub crra_sigma: f64,
+    /// Household discount factor `beta` used to solve the EGM policy.
+    pub discount_factor: f64,
+    /// Number of points in the EGM asset grid (shared for both the
+    /// end-of-period and beginning-of-period grid).
+    pub egm_grid_size: usize,
+    /// Upper bound of the EGM asset grid, in absolute currency units.
+    pub egm_asset_max: f64,
+    /// Maximum number of EGM policy-iteration sweeps per solve.
+    pub egm_max_iterations: usize,
+    /// Convergence tolerance (max absolute change in `c(a,y)` between
+    /// sweeps) below which the EGM solve stops early.
+    pub egm_tolerance: f64,
 
     // Bank behaviour
     pub capital_requirement: f64,
@@ -26,22 +113,87 @@ pub struct Config {
     pub lending_threshold: f64,
     pub risk_weight: f64,
 
+    // Interbank market
+    /// Spread over the policy rate charged on interbank loans.
+    pub interbank_spread: f64,
+    /// Fraction of interbank liabilities written off when a bank defaults.
+    pub interbank_default_fraction: f64,
+    /// Maximum countercyclical capital buffer (on top of `capital_buffer`).
+    pub countercyclical_buffer_max: f64,
+    /// How strongly the buffer responds to the credit-to-GDP gap.
+    pub countercyclical_buffer_sensitivity: f64,
+    /// Number of trailing periods used to estimate the credit-to-GDP trend.
+    pub credit_to_gdp_trend_periods: usize,
+
     // Labour market
     pub separation_rate: f64,
     pub matching_efficiency: f64,
     pub wage_stickiness: f64,
 
     // Credit market
-    pub default_rate_base: f64,
     pub rationing: bool,
+    /// Number of periods new loans amortize over.
+    pub loan_term_periods: u32,
+    /// Fraction of collateral value lost on liquidation (fire-sale discount).
+    pub collateral_haircut: f64,
+    /// Collateral pledged per unit borrowed at origination, capped by the
+    /// firm's own capital.
+    pub loan_collateral_coverage: f64,
+    /// Degree of capital-account openness, in `[0, 1]`: the share of a
+    /// domestically-rationed firm's unmet loan demand that the foreign pool
+    /// finances instead of the application being rejected outright.
+    pub zeta_k: f64,
+    /// Exogenous interest rate charged by the foreign pool on loans financed
+    /// under `zeta_k`.
+    pub world_int_rate: f64,
+    /// Skew of bequest-pool redistribution toward wealthier households, in
+    /// `[0, 1]`: 0 splits the pool uniformly, 1 splits it in proportion to
+    /// existing household wealth (see `EconomyState::distribute_bequests`).
+    pub zeta_bq: f64,
 
     // Government
     pub tax_rate_corporate: f64,
     pub tax_rate_income: f64,
+    /// Effective-tax-rate function applied to household taxable income:
+    /// either a bracketed marginal schedule or an OG-USA-style hyperbolic
+    /// saturating rate (see `TaxSchedule`). Defaults to a single bracket
+    /// reproducing `tax_rate_income` flat.
+    pub income_tax_schedule: TaxSchedule,
     pub spending_gdp_ratio: f64,
     pub unemployment_benefit_ratio: f64,
     pub deficit_target: f64,
     pub deficit_adjustment_speed: f64,
+    pub fiscal_rule_mode: FiscalRuleMode,
+    /// Steady-state debt-to-GDP ratio targeted by `FiscalRuleMode::DebtStabilization`.
+    pub debt_target_ratio: f64,
+    /// Speed at which the income-tax multiplier closes the debt/GDP gap.
+    pub debt_stabilization_speed: f64,
+    /// Sensitivity of the sovereign bond spread to the debt-to-GDP ratio.
+    pub sovereign_spread_sensitivity: f64,
+    /// Gouveia–Strauss `(phi, p, s)` applied to household wage income, layered
+    /// on top of `income_tax_schedule` (see `GovernmentData::gs_tax_liability`).
+    pub labor_tax_phi: f64,
+    pub labor_tax_curvature: f64,
+    pub labor_tax_scale: f64,
+    /// Gouveia–Strauss `(phi, p, s)` applied to capital/interest income —
+    /// household interest on `wealth` and firm profit alike.
+    pub capital_tax_phi: f64,
+    pub capital_tax_curvature: f64,
+    pub capital_tax_scale: f64,
+    /// Steady-state debt-to-GDP ratio targeted by `FiscalRuleMode::DebtClosure`.
+    pub debt_ratio_ss: f64,
+    /// Speed at which `FiscalRuleMode::DebtClosure` trims expenditure to
+    /// close the gap between `debt` and `debt_ratio_ss * gdp`.
+    pub fiscal_closure_gain: f64,
+    /// First period (by `EconomyState::current_period`) at which
+    /// `FiscalRuleMode::DebtClosure` starts adjusting expenditure, so an
+    /// unconstrained debt buildup can be studied before consolidation begins.
+    pub fiscal_closure_start_period: u64,
+    /// When set, `FiscalRuleMode::DebtClosure` cuts expenditure by the
+    /// primary deficit (expenditure + transfers - tax revenue) instead of
+    /// gradually closing the gap to `debt_ratio_ss`; debt service and
+    /// household interest are not covered, so the deficit can stay nonzero.
+    pub fiscal_closure_budget_balance: bool,
 
     // Central bank (Taylor rule)
     pub inflation_target: f64,
@@ -49,26 +201,98 @@ pub struct Config {
     pub output_gap_coefficient: f64,
     pub interest_rate_smoothing: f64,
     pub lower_bound: f64,
+    /// Smoothing factor for the exponential moving average of real GDP used
+    /// as the Taylor rule's trend/potential-output reference (see
+    /// `EconomyState::potential_gdp`); closer to 1 tracks trend more slowly.
+    pub potential_gdp_smoothing: f64,
+
+    // Execution
+    /// When true (and built with the `parallel` Cargo feature), firms and
+    /// households run their independent pre-market phase via a rayon
+    /// `par_iter_mut` batch pass (`EconomyState::step_firms_parallel` /
+    /// `step_households_parallel`) instead of one krabmaga-scheduled agent
+    /// step at a time. Both paths are deterministic (any RNG draws a batch
+    /// pass needs are pre-generated serially before the parallel sweep), so
+    /// this only affects throughput. Defaults to `false` so existing
+    /// single-threaded runs are unaffected.
+    pub parallel_agent_stepping: bool,
 
     // Sectors (for round-robin assignment)
     pub sectors: Vec<String>,
+    /// Index into `sectors` that supplies capital goods: firm investment
+    /// spend (see `FirmData::invest`) is routed here as goods-market demand
+    /// in `clear_goods_market`, so aggregate investment feeds those firms'
+    /// turnover instead of vanishing from the model uncounted.
+    pub capital_goods_sector_index: usize,
+    /// Technical-coefficient (input-output) matrix:
+    /// `io_coefficients[buyer_sector][input_sector]` is the quantity of
+    /// `input_sector`'s output consumed as an intermediate input per unit of
+    /// `buyer_sector`'s gross output. Square, sized `sectors.len()`.
+    pub io_coefficients: Vec<Vec<f64>>,
+    /// Required cash buffer, in months of outflows, for the largest firms
+    /// (buffer decays toward this floor as turnover grows; see
+    /// `FirmData::required_cash_buffer`).
+    pub buffer_months_min: f64,
+    /// Required cash buffer, in months of outflows, for the smallest firms.
+    pub buffer_months_max: f64,
+    /// Turnover scale controlling how quickly the required buffer decays
+    /// from `buffer_months_max` toward `buffer_months_min` as a firm grows.
+    pub buffer_size_scale: f64,
+
+    // Stock-flow-consistency audit
+    /// Maximum tolerated absolute residual (row or column) before the
+    /// consistency check logs a warning. Every row in
+    /// `accounting::build_flow_matrix` nets to zero against a real sector
+    /// counterparty except `bequests`' one-period accrual/distribution lag,
+    /// so this is set just above that residual rather than near-zero
+    /// floating-point epsilon.
+    pub sfc_tolerance: f64,
 }
 
 impl Default for Config {
     fn default() -> Self {
+        let sectors = vec![
+            "manufacturing".to_string(),
+            "construction".to_string(),
+            "retail_trade".to_string(),
+            "wholesale_trade".to_string(),
+            "professional_services".to_string(),
+            "financial_services".to_string(),
+            "real_estate".to_string(),
+            "information_technology".to_string(),
+            "healthcare".to_string(),
+            "accommodation_food".to_string(),
+            "transportation".to_string(),
+            "utilities".to_string(),
+            "other_services".to_string(),
+        ];
+
         Config {
             price_markup: 0.15,
             inventory_target_ratio: 0.2,
             capacity_utilization_target: 0.85,
             markup_adjustment_speed: 0.1,
+            capital_depreciation_rate: 0.025,
+            investment_sensitivity: 0.1,
 
-            consumption_smoothing: 0.7,
             job_search_intensity: 0.3,
             income_mean: 35_000.0,
             income_std: 15_000.0,
             wealth_shape: 2.0,
             mpc_mean: 0.8,
             mpc_std: 0.1,
+            mpc_min: 0.1,
+            productivity_persistence: 0.9,
+            productivity_shock_std: 0.2,
+            productivity_grid_points: 5,
+            productivity_tauchen_width: 3.0,
+            borrowing_limit_ratio: 0.5,
+            crra_sigma: 2.0,
+            discount_factor: 0.95,
+            egm_grid_size: 50,
+            egm_asset_max: 500_000.0,
+            egm_max_iterations: 200,
+            egm_tolerance: 1e-6,
 
             capital_requirement: 0.10,
             capital_buffer: 0.02,
@@ -77,41 +301,67 @@ impl Default for Config {
             lending_threshold: 0.3,
             risk_weight: 1.0,
 
+            interbank_spread: 0.01,
+            interbank_default_fraction: 0.4,
+            countercyclical_buffer_max: 0.025,
+            countercyclical_buffer_sensitivity: 0.5,
+            credit_to_gdp_trend_periods: 8,
+
             separation_rate: 0.05,
             matching_efficiency: 0.3,
             wage_stickiness: 0.8,
 
-            default_rate_base: 0.01,
             rationing: true,
+            loan_term_periods: 20,
+            collateral_haircut: 0.4,
+            loan_collateral_coverage: 1.2,
+            zeta_k: 0.0,
+            world_int_rate: 0.03,
+            zeta_bq: 0.0,
 
             tax_rate_corporate: 0.19,
             tax_rate_income: 0.20,
+            income_tax_schedule: TaxSchedule::Bracket(vec![(0.0, 0.20)]),
             spending_gdp_ratio: 0.40,
             unemployment_benefit_ratio: 0.4,
             deficit_target: 0.03,
             deficit_adjustment_speed: 0.1,
+            fiscal_rule_mode: FiscalRuleMode::DeficitTarget,
+            debt_target_ratio: 0.6,
+            debt_stabilization_speed: 0.05,
+            sovereign_spread_sensitivity: 0.02,
+            labor_tax_phi: 0.35,
+            labor_tax_curvature: 0.75,
+            labor_tax_scale: 0.001,
+            capital_tax_phi: 0.25,
+            capital_tax_curvature: 0.75,
+            capital_tax_scale: 0.002,
+            debt_ratio_ss: 0.6,
+            fiscal_closure_gain: 0.05,
+            fiscal_closure_start_period: 40,
+            fiscal_closure_budget_balance: false,
 
             inflation_target: 0.02,
             inflation_coefficient: 1.5,
             output_gap_coefficient: 0.5,
             interest_rate_smoothing: 0.8,
             lower_bound: 0.001,
+            potential_gdp_smoothing: 0.95,
+
+            parallel_agent_stepping: false,
+
+            sectors: sectors.clone(),
+            // "manufacturing" (sector 0) supplies capital goods.
+            capital_goods_sector_index: 0,
+            // Every sector buys a modest, uniform 1% of its own output from
+            // each sector (including itself) as an intermediate input — a
+            // simple reduced-form IO table pending real sectoral data.
+            io_coefficients: vec![vec![0.01; sectors.len()]; sectors.len()],
+            buffer_months_min: 1.0,
+            buffer_months_max: 4.0,
+            buffer_size_scale: 100_000.0,
 
-            sectors: vec![
-                "manufacturing".to_string(),
-                "construction".to_string(),
-                "retail_trade".to_string(),
-                "wholesale_trade".to_string(),
-                "professional_services".to_string(),
-                "financial_services".to_string(),
-                "real_estate".to_string(),
-                "information_technology".to_string(),
-                "healthcare".to_string(),
-                "accommodation_food".to_string(),
-                "transportation".to_string(),
-                "utilities".to_string(),
-                "other_services".to_string(),
-            ],
+            sfc_tolerance: 1.0,
         }
     }
 }
@@ -26,6 +26,7 @@ for r in records:
 ```
 */
 
+mod accounting;
 mod agents;
 mod config;
 mod markets;
@@ -58,6 +59,43 @@ pub struct PyPeriodRecord {
     pub total_lending: f64,
     pub firm_bankruptcies: usize,
     pub total_employment: usize,
+    pub effective_tax_rate: f64,
+    pub income_gini: f64,
+    /// Largest absolute stock-flow consistency residual this period; stays
+    /// near zero outside known simplifications (rationed demand,
+    /// uncollateralized defaults).
+    pub sfc_max_residual: f64,
+    /// Aggregate household financial-asset wealth at period end.
+    pub total_household_wealth: f64,
+    /// Gini coefficient of the household wealth distribution at period end.
+    pub wealth_gini: f64,
+    /// Tax revenue collected via the Gouveia–Strauss labor/capital subsystem.
+    pub gs_tax_revenue: f64,
+    /// Mean Gouveia–Strauss marginal tax rate across taxed households and firms.
+    pub average_marginal_tax_rate: f64,
+    /// Capital financed by the foreign pool this period under the
+    /// open-economy credit market; zero in the closed-economy default.
+    pub net_foreign_inflow: f64,
+    /// Steady-state debt level targeted by `FiscalRuleMode::DebtClosure`.
+    pub debt_target: f64,
+    /// Expenditure cut applied this period by `FiscalRuleMode::DebtClosure`.
+    pub fiscal_closure_adjustment: f64,
+    /// Residual firm net worth redistributed to households this period.
+    pub total_bequests: f64,
+    /// Fraction of this period's distributed bequests received by the
+    /// wealthiest decile of households.
+    pub bequest_top_decile_share: f64,
+    /// Nominal GDP (C + I + G, at current prices; see
+    /// `accounting::national_accounts`).
+    pub nominal_gdp: f64,
+    /// Nominal GDP deflated by `price_index`.
+    pub real_gdp: f64,
+    /// Price level relative to period 0 (`EconomyState::base_average_price`).
+    pub price_index: f64,
+    /// `(real_gdp - potential_gdp) / potential_gdp`, the measured output gap
+    /// the central bank observes this period; its Taylor rule reacts to it
+    /// at the start of the next period.
+    pub output_gap: f64,
 }
 
 #[pymethods]
@@ -86,6 +124,34 @@ impl PyPeriodRecord {
             self.firm_bankruptcies as f64,
         );
         m.insert("total_employment".to_string(), self.total_employment as f64);
+        m.insert("effective_tax_rate".to_string(), self.effective_tax_rate);
+        m.insert("income_gini".to_string(), self.income_gini);
+        m.insert("sfc_max_residual".to_string(), self.sfc_max_residual);
+        m.insert(
+            "total_household_wealth".to_string(),
+            self.total_household_wealth,
+        );
+        m.insert("wealth_gini".to_string(), self.wealth_gini);
+        m.insert("gs_tax_revenue".to_string(), self.gs_tax_revenue);
+        m.insert(
+            "average_marginal_tax_rate".to_string(),
+            self.average_marginal_tax_rate,
+        );
+        m.insert("net_foreign_inflow".to_string(), self.net_foreign_inflow);
+        m.insert("debt_target".to_string(), self.debt_target);
+        m.insert(
+            "fiscal_closure_adjustment".to_string(),
+            self.fiscal_closure_adjustment,
+        );
+        m.insert("total_bequests".to_string(), self.total_bequests);
+        m.insert(
+            "bequest_top_decile_share".to_string(),
+            self.bequest_top_decile_share,
+        );
+        m.insert("nominal_gdp".to_string(), self.nominal_gdp);
+        m.insert("real_gdp".to_string(), self.real_gdp);
+        m.insert("price_index".to_string(), self.price_index);
+        m.insert("output_gap".to_string(), self.output_gap);
         m
     }
 }
@@ -145,6 +211,22 @@ fn run_simulation(
             total_lending: r.total_lending,
             firm_bankruptcies: r.firm_bankruptcies,
             total_employment: r.total_employment,
+            effective_tax_rate: r.effective_tax_rate,
+            income_gini: r.income_gini,
+            sfc_max_residual: r.sfc_max_residual,
+            total_household_wealth: r.total_household_wealth,
+            wealth_gini: r.wealth_gini,
+            gs_tax_revenue: r.gs_tax_revenue,
+            average_marginal_tax_rate: r.average_marginal_tax_rate,
+            net_foreign_inflow: r.net_foreign_inflow,
+            debt_target: r.debt_target,
+            fiscal_closure_adjustment: r.fiscal_closure_adjustment,
+            total_bequests: r.total_bequests,
+            bequest_top_decile_share: r.bequest_top_decile_share,
+            nominal_gdp: r.nominal_gdp,
+            real_gdp: r.real_gdp,
+            price_index: r.price_index,
+            output_gap: r.output_gap,
         })
         .collect();
 
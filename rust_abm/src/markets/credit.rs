@@ -9,28 +9,46 @@ pub struct CreditOutcome {
     pub total_rejections: usize,
     pub average_rate: f64,
     pub total_defaults: usize,
+    /// Total outstanding principal written off across all defaulted loans.
+    pub total_default_principal: f64,
+    /// Total shortfall (uncollateralized portion) booked as a capital loss.
+    pub total_default_loss: f64,
+    /// Capital financed by the foreign pool this period when domestic banks
+    /// ration (`Config::zeta_k` of openness); zero in the closed-economy
+    /// default (`zeta_k == 0.0`). Not booked against any bank's loan book;
+    /// the counterparty is the rest-of-world sector's claim on firms
+    /// (`EconomyState::rest_of_world_claims`), not a domestic bank.
+    pub net_foreign_inflow: f64,
 }
 
 /// Clear the credit market in-place on the simulation state.
 ///
 /// Mirrors Python `CreditMarket.clear()`:
-/// 1. Process defaults from bankrupt firms.
+/// 1. Liquidate bankrupt firms' loans, bank by bank.
 /// 2. Identify firms with negative cash (credit demand).
 /// 3. Match firms to banks (round-robin).
-/// 4. Banks evaluate and extend or reject loans.
+/// 4. Banks evaluate and extend or reject loans, each a new amortizing,
+///    collateralized `Loan` on the bank's loan book.
 pub fn clear_credit_market(state: &mut EconomyState) {
-    let default_base = state.config.default_rate_base;
     let rationing = state.config.rationing;
     let cap_req = state.config.capital_requirement;
     let cap_buf = state.config.capital_buffer;
     let risk_w = state.config.risk_weight;
     let lend_thresh = state.config.lending_threshold;
+    let loan_term = state.config.loan_term_periods;
+    let haircut = state.config.collateral_haircut;
+    let collateral_coverage = state.config.loan_collateral_coverage;
+    let zeta_k = state.config.zeta_k;
+    let world_int_rate = state.config.world_int_rate;
 
     let mut total_lending = 0.0f64;
     let mut total_applications = 0usize;
     let mut total_approvals = 0usize;
     let mut total_rejections = 0usize;
     let mut total_defaults = 0usize;
+    let mut total_default_principal = 0.0f64;
+    let mut total_default_loss = 0.0f64;
+    let mut net_foreign_inflow = 0.0f64;
     let mut rates: Vec<f64> = Vec::new();
 
     let n_banks = state.banks.len();
@@ -39,16 +57,46 @@ pub fn clear_credit_market(state: &mut EconomyState) {
         return;
     }
 
-    // ── 1. Process defaults ──────────────────────────────────────────────────
+    // ── 1. Process defaults: liquidate every bankrupt firm's loans at each
+    // bank that holds one, booking only the collateral shortfall to capital,
+    // then forgive the firm's own debt now that every loan against it is gone.
     for firm_idx in 0..state.firms.len() {
-        if state.firms[firm_idx].bankrupt && state.firms[firm_idx].debt > 0.0 {
-            let firm_debt = state.firms[firm_idx].debt;
+        if state.firms[firm_idx].bankrupt {
+            let mut recovered_collateral = 0.0;
             for bank in state.banks.iter_mut() {
-                if bank.loans > 0.0 {
-                    let share = firm_debt.min(bank.loans);
-                    bank.record_default(share * default_base);
-                    total_defaults += 1;
-                }
+                let (liquidated, principal, loss, recovered) =
+                    bank.liquidate_borrower_loans(firm_idx, haircut);
+                total_defaults += liquidated;
+                total_default_principal += principal;
+                total_default_loss += loss;
+                recovered_collateral += recovered;
+            }
+            state.firms[firm_idx].debt = 0.0;
+            // The collateral banks just seized is no longer the firm's
+            // asset — debit it from capital so the bequest sweep below only
+            // captures genuinely unencumbered net worth, not value already
+            // recovered by a lender (see `accounting::build_flow_matrix`'s
+            // `defaults` row).
+            state.firms[firm_idx].capital =
+                (state.firms[firm_idx].capital - recovered_collateral).max(0.0);
+
+            // Sweep any residual net worth into the bequest pool for
+            // redistribution to households next period (see
+            // `EconomyState::distribute_bequests`), once per firm.
+            if !state.firms[firm_idx].bequest_collected {
+                let residual =
+                    (state.firms[firm_idx].cash + state.firms[firm_idx].capital).max(0.0);
+                state.bequest_pool += residual;
+                state.bequest_accrued_this_period += residual;
+                state.firms[firm_idx].cash = 0.0;
+                state.firms[firm_idx].capital = 0.0;
+                // `invest()` is never called again once a firm is bankrupt
+                // (see `step_firm_independent`), so without this its last
+                // recorded `investment` would stay on its books
+                // (`clear_goods_market` and the period record skip bankrupt
+                // firms' investment regardless).
+                state.firms[firm_idx].investment = 0.0;
+                state.firms[firm_idx].bequest_collected = true;
             }
         }
     }
@@ -81,12 +129,26 @@ pub fn clear_credit_market(state: &mut EconomyState) {
         );
 
         if approved || !rationing {
-            let rate = state.banks[b_idx].extend_loan(amount);
+            let collateral_value =
+                (amount * collateral_coverage).min(state.firms[firm_idx].capital.max(0.0));
+            let rate =
+                state.banks[b_idx].extend_loan(firm_idx, amount, loan_term, collateral_value);
             state.firms[firm_idx].cash += amount;
             state.firms[firm_idx].debt += amount;
             total_approvals += 1;
             total_lending += amount;
             rates.push(rate);
+        } else if zeta_k > 0.0 {
+            // Open economy: the foreign pool finances a `zeta_k` share of the
+            // domestically-rationed demand at the world interest rate,
+            // rather than the application being rejected outright.
+            let foreign_amount = zeta_k * amount;
+            state.firms[firm_idx].cash += foreign_amount;
+            state.firms[firm_idx].debt += foreign_amount;
+            total_approvals += 1;
+            total_lending += foreign_amount;
+            net_foreign_inflow += foreign_amount;
+            rates.push(world_int_rate);
         } else {
             total_rejections += 1;
         }
@@ -105,5 +167,8 @@ pub fn clear_credit_market(state: &mut EconomyState) {
         total_rejections,
         average_rate,
         total_defaults,
+        total_default_principal,
+        total_default_loss,
+        net_foreign_inflow,
     };
 }
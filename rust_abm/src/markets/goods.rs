@@ -7,74 +7,223 @@ pub struct GoodsOutcome {
     pub average_price: f64,
     pub excess_demand: f64,
     pub inflation: f64,
+    /// Sales value realised within each sector this period, indexed the same
+    /// way as `Config::sectors` / `FirmData::sector_index`.
+    pub sector_sales: Vec<f64>,
+    /// Gross output value (quantity × price) produced within each sector
+    /// this period, before sales are matched against it.
+    pub sector_output: Vec<f64>,
+    /// Mean firm price within each sector, used by `FirmData::update_financials`
+    /// (lagged one period) to value intermediate-input purchases at their
+    /// sellers' prices.
+    pub sector_price: Vec<f64>,
+    /// `sector_sales / sector_demand` within each sector, clamped to `[0, 1]`:
+    /// how much of the demand placed on that sector (final + intermediate)
+    /// was actually filled. Read (lagged) by `FirmAgent::step` to throttle
+    /// realized output when upstream sectors can't supply everything firms
+    /// asked for, and exposed economy-wide via
+    /// `accounting::NationalAccounts::sector_demand_satisfaction`.
+    pub sector_fill_ratio: Vec<f64>,
+    /// Household consumption demand placed this period (C), and the portion
+    /// of it actually filled — each sector's `sector_fill_ratio` applied to
+    /// that sector's share of consumption demand.
+    pub consumption_demand: f64,
+    pub consumption_fulfilled: f64,
+    /// Firm investment demand placed this period (I; see `FirmData::invest`),
+    /// and the portion actually filled.
+    pub investment_demand: f64,
+    pub investment_fulfilled: f64,
+    /// Government spending demand placed this period (G), and the portion
+    /// actually filled.
+    pub government_demand: f64,
+    pub government_fulfilled: f64,
+    /// Intermediate-goods demand placed by firms on one another this period,
+    /// and the portion actually filled.
+    pub intermediate_demand_total: f64,
+    pub intermediate_fulfilled: f64,
 }
 
 /// Clear the goods market in-place on the simulation state.
 ///
-/// Mirrors the Python `GoodsMarket.clear()` logic exactly:
-/// 1. Compute total demand (households + government).
-/// 2. Compute total supply (firm inventory × price).
-/// 3. Allocate demand across firms proportionally to price-competitiveness.
-/// 4. Update firm turnover / inventory and compute inflation.
+/// Markets clear per-sector rather than economy-wide: each sector's firms
+/// compete only against each other, final demand (households + government)
+/// is split across sectors by supply share, each sector also absorbs
+/// intermediate demand placed on it by every buyer sector via
+/// `Config::io_coefficients[buyer_sector][input_sector]`, and aggregate firm
+/// investment (see `FirmData::invest`) lands entirely on
+/// `Config::capital_goods_sector_index` rather than being split like final
+/// demand.
+///
+/// 1. Compute each sector's gross output and the economy-wide intermediate
+///    demand this implies (`intermediate_demand[input] = sum over buyers of
+///    io[buyer][input] * gross_output[buyer]`).
+/// 2. Split final demand across sectors proportional to supply share.
+/// 3. Within each sector, allocate its demand across firms proportionally to
+///    price-competitiveness (as before).
+/// 4. Update firm turnover / inventory and compute inflation, along with the
+///    mean price and demand fill-ratio per sector that `FirmAgent::step`
+///    reads (lagged) to cost and throttle next period's production.
 pub fn clear_goods_market(state: &mut EconomyState) {
     let previous_average_price = state.goods_average_price;
+    let n_sectors = state.config.sectors.len();
 
     let active_indices: Vec<usize> = (0..state.firms.len())
         .filter(|&i| !state.firms[i].bankrupt)
         .collect();
 
-    // ── Demand side ──────────────────────────────────────────────────────────
-    let total_demand: f64 = state.households.iter().map(|h| h.consumption).sum::<f64>()
-        + state.government.expenditure;
-
-    // ── Supply side ──────────────────────────────────────────────────────────
-    let total_supply: f64 = active_indices
-        .iter()
-        .map(|&i| state.firms[i].inventory * state.firms[i].price)
-        .sum();
-
-    let excess_demand = total_demand - total_supply;
-
     if active_indices.is_empty() {
         state.goods_average_price = previous_average_price;
         state.goods_last = GoodsOutcome {
             total_sales: 0.0,
             average_price: previous_average_price,
-            excess_demand,
+            excess_demand: 0.0,
             inflation: 0.0,
+            sector_sales: vec![0.0; n_sectors],
+            sector_output: vec![0.0; n_sectors],
+            sector_price: vec![previous_average_price; n_sectors],
+            sector_fill_ratio: vec![1.0; n_sectors],
+            consumption_demand: 0.0,
+            consumption_fulfilled: 0.0,
+            investment_demand: 0.0,
+            investment_fulfilled: 0.0,
+            government_demand: 0.0,
+            government_fulfilled: 0.0,
+            intermediate_demand_total: 0.0,
+            intermediate_fulfilled: 0.0,
         };
         return;
     }
 
-    // ── Matching: allocate demand proportional to competitiveness ────────────
-    let max_price = active_indices
-        .iter()
-        .map(|&i| state.firms[i].price)
-        .fold(f64::NEG_INFINITY, f64::max);
+    // ── Gross output by sector (value) ───────────────────────────────────────
+    let mut sector_output = vec![0.0f64; n_sectors];
+    for &i in &active_indices {
+        let firm = &state.firms[i];
+        sector_output[firm.sector_index] += firm.output * firm.price;
+    }
 
-    let weights: Vec<f64> = active_indices
-        .iter()
-        .map(|&i| (max_price - state.firms[i].price + 1e-9).max(1e-9))
+    // ── Intermediate demand placed on each input sector by every buyer ───────
+    let io = &state.config.io_coefficients;
+    let intermediate_demand: Vec<f64> = (0..n_sectors)
+        .map(|input_idx| {
+            (0..n_sectors)
+                .map(|buyer_idx| io[buyer_idx][input_idx] * sector_output[buyer_idx])
+                .sum()
+        })
         .collect();
-    let weight_sum: f64 = weights.iter().sum();
 
-    let mut total_sales = 0.0f64;
+    // ── Final demand (households + government) ──────────────────────────────
+    let household_demand_total: f64 = state.households.iter().map(|h| h.consumption).sum();
+    let government_demand_total = state.government.expenditure;
+    let final_demand = household_demand_total + government_demand_total;
+
+    // Firm investment spend (see `FirmData::invest`) is demand for capital
+    // goods, routed entirely to `Config::capital_goods_sector_index` rather
+    // than split across sectors like final consumption/government demand.
+    // Restricted to `active_indices` so no bankrupt firm's `investment`
+    // counts as capital-goods demand (`clear_credit_market` also zeroes it
+    // when it sweeps the firm into the bequest pool).
+    let total_investment: f64 = active_indices.iter().map(|&i| state.firms[i].investment).sum();
+    let capital_goods_sector = state.config.capital_goods_sector_index.min(n_sectors - 1);
+
+    let total_supply: f64 = active_indices
+        .iter()
+        .map(|&i| state.firms[i].inventory * state.firms[i].price)
+        .sum();
+
+    let total_demand: f64 =
+        final_demand + total_investment + intermediate_demand.iter().sum::<f64>();
+    let excess_demand = total_demand - total_supply;
+
     let markup_speed = state.config.markup_adjustment_speed;
+    let mut total_sales = 0.0f64;
+    let mut sector_sales = vec![0.0f64; n_sectors];
+    let mut sector_price = vec![previous_average_price; n_sectors];
+    let mut sector_fill_ratio = vec![1.0f64; n_sectors];
 
-    for (weight, &firm_idx) in weights.iter().zip(active_indices.iter()) {
-        let share = weight / weight_sum;
-        let demand_for_firm = total_demand * share;
-        let available = state.firms[firm_idx].inventory * state.firms[firm_idx].price;
-        let actual_sales = demand_for_firm.min(available);
+    // National-accounts demand/fulfilled totals by expenditure category (see
+    // `accounting::NationalAccounts`). Within a sector, every demand source
+    // is pooled before competing for supply, so each category is assumed
+    // filled in proportion to the sector's overall `sector_fill_ratio`.
+    let mut consumption_fulfilled = 0.0f64;
+    let mut investment_fulfilled = 0.0f64;
+    let mut government_fulfilled = 0.0f64;
+    let mut intermediate_fulfilled = 0.0f64;
 
-        let quantity_sold = actual_sales / state.firms[firm_idx].price.max(1e-9);
-        state.firms[firm_idx].inventory = (state.firms[firm_idx].inventory - quantity_sold).max(0.0);
-        state.firms[firm_idx].turnover = actual_sales;
-        total_sales += actual_sales;
+    // ── Per-sector sub-markets ────────────────────────────────────────────────
+    for sector_idx in 0..n_sectors {
+        let sector_firms: Vec<usize> = active_indices
+            .iter()
+            .copied()
+            .filter(|&i| state.firms[i].sector_index == sector_idx)
+            .collect();
+        if sector_firms.is_empty() {
+            continue;
+        }
 
-        // Markup adaptation
-        let firm_excess = (demand_for_firm - available) / available.max(1e-9);
-        state.firms[firm_idx].adapt_markup(firm_excess, markup_speed);
+        let sector_supply: f64 = sector_firms
+            .iter()
+            .map(|&i| state.firms[i].inventory * state.firms[i].price)
+            .sum();
+        let supply_share = if total_supply > 0.0 {
+            sector_supply / total_supply
+        } else {
+            1.0 / n_sectors as f64
+        };
+        let capital_demand = if sector_idx == capital_goods_sector {
+            total_investment
+        } else {
+            0.0
+        };
+        let consumption_demand_sector = household_demand_total * supply_share;
+        let government_demand_sector = government_demand_total * supply_share;
+        let sector_demand = consumption_demand_sector
+            + government_demand_sector
+            + intermediate_demand[sector_idx]
+            + capital_demand;
+
+        let max_price = sector_firms
+            .iter()
+            .map(|&i| state.firms[i].price)
+            .fold(f64::NEG_INFINITY, f64::max);
+        let weights: Vec<f64> = sector_firms
+            .iter()
+            .map(|&i| (max_price - state.firms[i].price + 1e-9).max(1e-9))
+            .collect();
+        let weight_sum: f64 = weights.iter().sum();
+
+        for (weight, &firm_idx) in weights.iter().zip(sector_firms.iter()) {
+            let share = weight / weight_sum;
+            let demand_for_firm = sector_demand * share;
+            let available = state.firms[firm_idx].inventory * state.firms[firm_idx].price;
+            let actual_sales = demand_for_firm.min(available);
+
+            let quantity_sold = actual_sales / state.firms[firm_idx].price.max(1e-9);
+            state.firms[firm_idx].inventory =
+                (state.firms[firm_idx].inventory - quantity_sold).max(0.0);
+            state.firms[firm_idx].turnover = actual_sales;
+            total_sales += actual_sales;
+            sector_sales[sector_idx] += actual_sales;
+
+            // Markup adaptation
+            let firm_excess = (demand_for_firm - available) / available.max(1e-9);
+            state.firms[firm_idx].adapt_markup(firm_excess, markup_speed);
+        }
+
+        sector_price[sector_idx] = sector_firms
+            .iter()
+            .map(|&i| state.firms[i].price)
+            .sum::<f64>()
+            / sector_firms.len() as f64;
+        sector_fill_ratio[sector_idx] = if sector_demand > 0.0 {
+            (sector_sales[sector_idx] / sector_demand).min(1.0)
+        } else {
+            1.0
+        };
+        let fill = sector_fill_ratio[sector_idx];
+        consumption_fulfilled += consumption_demand_sector * fill;
+        government_fulfilled += government_demand_sector * fill;
+        intermediate_fulfilled += intermediate_demand[sector_idx] * fill;
+        investment_fulfilled += capital_demand * fill;
     }
 
     // ── Average price and inflation ──────────────────────────────────────────
@@ -96,5 +245,17 @@ pub fn clear_goods_market(state: &mut EconomyState) {
         average_price: avg_price,
         excess_demand,
         inflation,
+        sector_sales,
+        sector_output,
+        sector_price,
+        sector_fill_ratio,
+        consumption_demand: household_demand_total,
+        consumption_fulfilled,
+        investment_demand: total_investment,
+        investment_fulfilled,
+        government_demand: government_demand_total,
+        government_fulfilled,
+        intermediate_demand_total: intermediate_demand.iter().sum(),
+        intermediate_fulfilled,
     };
 }
@@ -0,0 +1,182 @@
+use crate::state::EconomyState;
+
+/// Outcome of interbank market clearing (and any resulting default cascade)
+/// for one period.
+#[derive(Clone, Debug, Default)]
+pub struct InterbankOutcome {
+    /// Directed exposures `exposures[lender][borrower]`.
+    pub exposures: Vec<Vec<f64>>,
+    pub total_interbank_lending: f64,
+    pub bank_failures: usize,
+    pub contagion_losses: f64,
+}
+
+/// Countercyclical capital buffer: widens the capital requirement when bank
+/// credit is running ahead of its own trailing trend (a simple proxy for
+/// the Basel III credit-to-GDP gap), narrows it otherwise.
+fn countercyclical_buffer(state: &EconomyState) -> f64 {
+    let gdp = state.government.gdp_estimate;
+    if gdp <= 0.0 {
+        return 0.0;
+    }
+    let current_credit: f64 = state.banks.iter().map(|b| b.loans).sum();
+    let current_ratio = current_credit / gdp;
+
+    let window = state.config.credit_to_gdp_trend_periods.max(1);
+    let history: Vec<f64> = state
+        .records
+        .iter()
+        .rev()
+        .take(window)
+        .filter(|r| r.gdp > 0.0)
+        .map(|r| r.bank_credit_stock / r.gdp)
+        .collect();
+
+    if history.is_empty() {
+        return 0.0;
+    }
+    let trend = history.iter().sum::<f64>() / history.len() as f64;
+    let gap = current_ratio - trend;
+
+    (gap * state.config.countercyclical_buffer_sensitivity)
+        .clamp(0.0, state.config.countercyclical_buffer_max)
+}
+
+/// Clear the interbank market and resolve any resulting contagion.
+///
+/// Mirrors a simplified Basel-style liquidity/capital regime: banks with
+/// reserves above their requirement lend to banks short of it, forming a
+/// directed exposure matrix. When a bank's `capital_ratio` (already
+/// depressed by firm defaults processed in `clear_credit_market`) falls
+/// below `capital_requirement` plus `capital_buffer` and the countercyclical
+/// buffer, it defaults
+/// on a fraction of its interbank liabilities; losses are pushed to
+/// creditor banks' capital and the check repeats (a Furfine-style
+/// sequential cascade) until no new bank fails.
+pub fn clear_interbank_market(state: &mut EconomyState) -> InterbankOutcome {
+    let n = state.banks.len();
+    let mut exposures = vec![vec![0.0; n]; n];
+    if n < 2 {
+        state.interbank_exposures = exposures.clone();
+        return InterbankOutcome {
+            exposures,
+            ..Default::default()
+        };
+    }
+
+    let cap_req = state.config.capital_requirement;
+    let cap_buf = state.config.capital_buffer + countercyclical_buffer(state);
+    let risk_w = state.config.risk_weight;
+    let rate = state.central_bank.policy_rate + state.config.interbank_spread;
+
+    for bank in state.banks.iter_mut() {
+        bank.interbank_rate = rate;
+    }
+
+    let mut remaining_surplus: Vec<f64> = state
+        .banks
+        .iter()
+        .map(|b| (b.reserves - b.loans * cap_req).max(0.0))
+        .collect();
+    // Use the same risk-weighted basis as `meets_capital_requirement` below
+    // (per-loan collateral coverage and maturity, not a flat weight against
+    // gross `loans`), so a bank's computed interbank borrowing need always
+    // matches the capital ratio that will actually be checked against it.
+    let shortfall: Vec<f64> = state
+        .banks
+        .iter()
+        .map(|b| (b.risk_weighted_assets(risk_w) * (cap_req + cap_buf) - b.capital).max(0.0))
+        .collect();
+
+    let mut total_lending = 0.0;
+    let mut lender_cursor = 0usize;
+
+    for borrower in 0..n {
+        let mut need = shortfall[borrower];
+        if need <= 0.0 {
+            continue;
+        }
+        let mut attempts = 0;
+        while need > 0.0 && attempts < n {
+            let lender = lender_cursor % n;
+            lender_cursor += 1;
+            attempts += 1;
+            if lender == borrower || remaining_surplus[lender] <= 0.0 {
+                continue;
+            }
+            let amount = need.min(remaining_surplus[lender]);
+            exposures[lender][borrower] += amount;
+            remaining_surplus[lender] -= amount;
+            need -= amount;
+            total_lending += amount;
+
+            state.banks[lender].reserves -= amount;
+            state.banks[lender].interbank_assets += amount;
+            state.banks[borrower].reserves += amount;
+            state.banks[borrower].interbank_liabilities += amount;
+        }
+    }
+
+    state.interbank_exposures = exposures.clone();
+
+    let (bank_failures, contagion_losses) =
+        run_default_cascade(state, &mut exposures, cap_req, cap_buf, risk_w);
+
+    InterbankOutcome {
+        exposures,
+        total_interbank_lending: total_lending,
+        bank_failures,
+        contagion_losses,
+    }
+}
+
+/// Sequentially fail banks that breach the capital requirement, writing off
+/// a fraction of each failed bank's interbank liabilities and passing the
+/// loss to its creditors pro-rata to their exposure. Repeats until a full
+/// pass finds no new failures (the cascade fixed point).
+fn run_default_cascade(
+    state: &mut EconomyState,
+    exposures: &mut [Vec<f64>],
+    cap_req: f64,
+    cap_buf: f64,
+    risk_w: f64,
+) -> (usize, f64) {
+    let n = state.banks.len();
+    let mut failed = vec![false; n];
+    let mut total_losses = 0.0;
+    let default_fraction = state.config.interbank_default_fraction;
+
+    loop {
+        let newly_failed: Vec<usize> = (0..n)
+            .filter(|&i| {
+                !failed[i] && !state.banks[i].meets_capital_requirement(cap_req, cap_buf, risk_w)
+            })
+            .collect();
+        if newly_failed.is_empty() {
+            break;
+        }
+
+        for &i in &newly_failed {
+            failed[i] = true;
+            let written_off = state.banks[i].interbank_liabilities * default_fraction;
+            let total_exposure_to_i: f64 = (0..n).map(|lender| exposures[lender][i]).sum();
+
+            if total_exposure_to_i > 0.0 {
+                for lender in 0..n {
+                    let exposure = exposures[lender][i];
+                    if exposure <= 0.0 {
+                        continue;
+                    }
+                    let loss = written_off * (exposure / total_exposure_to_i);
+                    state.banks[lender].capital -= loss;
+                    total_losses += loss;
+                }
+            }
+
+            state.banks[i].interbank_liabilities =
+                (state.banks[i].interbank_liabilities - written_off).max(0.0);
+        }
+    }
+
+    (failed.iter().filter(|&&f| f).count(), total_losses)
+}
@@ -1,7 +1,9 @@
 pub mod credit;
 pub mod goods;
+pub mod interbank;
 pub mod labor;
 
 pub use credit::{clear_credit_market, CreditOutcome};
 pub use goods::{clear_goods_market, GoodsOutcome};
+pub use interbank::{clear_interbank_market, InterbankOutcome};
 pub use labor::{LaborMarketAgent, LaborOutcome};
@@ -5,11 +5,17 @@ use rand::{Rng, SeedableRng};
 use rand::rngs::StdRng;
 use rand_distr::{Distribution, LogNormal, Normal, Pareto};
 
-use crate::agents::{BankData, CentralBankData, FirmAgent, FirmData, GovernmentData, HouseholdAgent, HouseholdData};
+use crate::accounting::{self, NationalAccounts, SectorBalanceSheet, SfcOutcome};
+#[cfg(feature = "parallel")]
+use crate::agents::step_firm_independent;
+use crate::agents::{
+    solve_egm_policy, tauchen, BankData, CentralBankData, FirmAgent, FirmData, GovernmentData,
+    HouseholdAgent, HouseholdData,
+};
 use crate::config::Config;
 use crate::markets::{
-    clear_credit_market, clear_goods_market, CreditOutcome, GoodsOutcome, LaborMarketAgent,
-    LaborOutcome,
+    clear_credit_market, clear_goods_market, clear_interbank_market, CreditOutcome, GoodsOutcome,
+    InterbankOutcome, LaborMarketAgent, LaborOutcome,
 };
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -30,6 +36,66 @@ pub struct PeriodRecord {
     pub total_lending: f64,
     pub firm_bankruptcies: usize,
     pub total_employment: usize,
+    /// Aggregate household income tax collected divided by aggregate pre-tax
+    /// household income (rises with income under the bracketed schedule).
+    pub effective_tax_rate: f64,
+    /// Gini coefficient of the post-tax household income distribution.
+    pub income_gini: f64,
+    /// Total outstanding bank loans (credit stock) at period end.
+    pub bank_credit_stock: f64,
+    /// Number of banks that breached the capital requirement and defaulted
+    /// on interbank liabilities this period.
+    pub bank_failures: usize,
+    /// Total losses absorbed by creditor banks in the contagion cascade.
+    pub interbank_contagion_losses: f64,
+    /// Sovereign bond rate charged on government debt this period.
+    pub r_gov: f64,
+    /// Debt service (`r_gov * debt`) paid this period.
+    pub debt_service: f64,
+    /// Government debt divided by estimated GDP.
+    pub debt_to_gdp: f64,
+    /// Average realized marginal propensity to consume across households.
+    pub average_mpc: f64,
+    /// Aggregate gross investment spent by firms this period.
+    pub total_investment: f64,
+    /// Aggregate household financial-asset wealth at period end.
+    pub total_household_wealth: f64,
+    /// Gini coefficient of the household wealth distribution at period end.
+    pub wealth_gini: f64,
+    /// Largest absolute stock-flow consistency residual this period (see
+    /// `accounting::check_consistency`); should stay near zero outside
+    /// known simplifications (rationed demand, uncollateralized defaults).
+    pub sfc_max_residual: f64,
+    /// Tax revenue collected via the Gouveia–Strauss labor/capital subsystem.
+    pub gs_tax_revenue: f64,
+    /// Mean Gouveia–Strauss marginal tax rate across taxed households and firms.
+    pub average_marginal_tax_rate: f64,
+    /// Capital financed by the foreign pool this period under the
+    /// open-economy credit market (`Config::zeta_k`); zero in the
+    /// closed-economy default.
+    pub net_foreign_inflow: f64,
+    /// Steady-state debt level (`debt_ratio_ss * gdp`) targeted by
+    /// `FiscalRuleMode::DebtClosure`.
+    pub debt_target: f64,
+    /// Expenditure cut applied this period by `FiscalRuleMode::DebtClosure`
+    /// (zero under the other fiscal rule modes).
+    pub fiscal_closure_adjustment: f64,
+    /// Residual firm net worth redistributed to households this period (see
+    /// `EconomyState::distribute_bequests`).
+    pub total_bequests: f64,
+    /// Fraction of this period's distributed bequests received by the
+    /// wealthiest decile of households.
+    pub bequest_top_decile_share: f64,
+    /// Nominal GDP at current prices (consumption + investment + government
+    /// spending actually transacted; see `accounting::national_accounts`).
+    pub nominal_gdp: f64,
+    /// Nominal GDP deflated by `price_index`.
+    pub real_gdp: f64,
+    /// Goods-market average price relative to period 0.
+    pub price_index: f64,
+    /// Real GDP relative to trend (`EconomyState::potential_gdp`), the
+    /// measure the central bank's Taylor rule now reacts to.
+    pub output_gap: f64,
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -51,9 +117,86 @@ pub struct EconomyState {
 
     // Market outcomes (updated each period)
     pub goods_average_price: f64,
+    /// Goods-market average price at period 0, held fixed as the base-year
+    /// price level used to deflate nominal GDP into real GDP (see
+    /// `accounting::national_accounts`).
+    pub base_average_price: f64,
     pub goods_last: GoodsOutcome,
     pub labor_last: LaborOutcome,
     pub credit_last: CreditOutcome,
+    pub interbank_last: InterbankOutcome,
+    pub interbank_exposures: Vec<Vec<f64>>,
+
+    // Stock-flow-consistency accounting
+    pub previous_balance_sheet: SectorBalanceSheet,
+    pub consistency_last: SfcOutcome,
+
+    /// Expenditure-side GDP decomposition for the period just completed
+    /// (see `accounting::national_accounts`).
+    pub national_accounts_last: NationalAccounts,
+    /// Trend (potential) real GDP, an exponential moving average of
+    /// `national_accounts_last.real_gdp` (see `Config::potential_gdp_smoothing`).
+    /// The central bank's Taylor rule output-gap term is
+    /// `(real_gdp - potential_gdp) / potential_gdp`.
+    pub potential_gdp: f64,
+
+    // Tax outcomes from the most recently completed period
+    pub effective_tax_rate: f64,
+    pub income_gini: f64,
+    /// Tax revenue collected via the Gouveia–Strauss labor/capital subsystem
+    /// (a subset of `government.tax_revenue`; excludes the bracketed
+    /// household schedule and the flat corporate rate).
+    pub gs_tax_revenue: f64,
+    /// Mean Gouveia–Strauss marginal tax rate across taxed households (wage
+    /// income) and firms (profit) this period.
+    pub average_marginal_tax_rate: f64,
+
+    // Government debt service from the most recently completed period
+    pub r_gov: f64,
+    pub debt_service: f64,
+    /// Steady-state debt level (`debt_ratio_ss * gdp`) targeted by
+    /// `FiscalRuleMode::DebtClosure`.
+    pub debt_target: f64,
+    /// Expenditure cut applied this period by `FiscalRuleMode::DebtClosure`
+    /// (zero under the other fiscal rule modes).
+    pub fiscal_closure_adjustment: f64,
+
+    /// Cumulative foreign-pool lending to domestic firms under `Config::zeta_k`
+    /// (see `CreditOutcome::net_foreign_inflow`): the rest-of-world sector's
+    /// claim on firms, and its counterpart in `accounting::balance_sheet`.
+    /// Never amortized in this model (foreign-financed loans aren't added to
+    /// any bank's loan book), so it grows monotonically for as long as
+    /// `zeta_k` lending occurs.
+    pub rest_of_world_claims: f64,
+
+    // Bequest pool: residual net worth swept from bankrupt firms (see
+    // `markets::clear_credit_market`), redistributed to households one
+    // period later by `EconomyState::distribute_bequests`.
+    /// Running stock awaiting redistribution at the start of next period.
+    pub bequest_pool: f64,
+    /// Amount added to the pool this period (for the SFC audit's firm-column
+    /// reconciliation).
+    pub bequest_accrued_this_period: f64,
+    /// Amount distributed to households this period (drained from last
+    /// period's pool).
+    pub total_bequests: f64,
+    /// Fraction of this period's distributed bequests received by the
+    /// wealthiest decile of households.
+    pub bequest_top_decile_share: f64,
+
+    // Idiosyncratic productivity process shared by all households (Tauchen
+    // discretization of an AR(1) log-productivity process; see
+    // `agents::household::tauchen`).
+    pub productivity_grid: Vec<f64>,
+    pub productivity_transition: Vec<Vec<f64>>,
+
+    /// Endogenous-grid consumption-savings policy `c(a, y)`, re-solved each
+    /// period in `run_pre_step` for that period's interest rate (see
+    /// `agents::household::solve_egm_policy`). Shared asset grid for every
+    /// income state `y`; households look up consumption at their own
+    /// `(wealth, productivity_state)` in `HouseholdAgent::step`.
+    pub egm_asset_grid: Vec<f64>,
+    pub egm_consumption_policy: Vec<Vec<f64>>,
 
     // Configuration
     pub config: Config,
@@ -75,8 +218,18 @@ impl EconomyState {
     /// Create a new economy state and immediately initialise agent data.
     pub fn new(n_firms: usize, n_households: usize, n_banks: usize, seed: u64, config: Config) -> Self {
         let mut rng = StdRng::seed_from_u64(seed);
+        let (productivity_log_grid, productivity_transition) = tauchen(
+            config.productivity_persistence,
+            config.productivity_shock_std,
+            config.productivity_grid_points,
+            config.productivity_tauchen_width,
+        );
+        let productivity_grid: Vec<f64> = productivity_log_grid.iter().map(|e| e.exp()).collect();
+        let n_sectors = config.sectors.len();
+
         let firms = Self::create_firms(n_firms, &config, &mut rng);
-        let households = Self::create_households(n_households, &config, &mut rng);
+        let households =
+            Self::create_households(n_households, &config, &productivity_grid, &mut rng);
         let banks = Self::create_banks(n_banks, &mut rng);
         let central_bank = CentralBankData::new(config.inflation_target);
         let government = GovernmentData::new();
@@ -88,9 +241,40 @@ impl EconomyState {
             central_bank,
             government,
             goods_average_price: 1.0,
-            goods_last: GoodsOutcome::default(),
+            base_average_price: 1.0,
+            // Sector prices/fill-ratios are read by `FirmAgent::step` before
+            // the goods market has ever cleared, so they must start
+            // plausibly (unit price, fully available) rather than empty.
+            goods_last: GoodsOutcome {
+                sector_price: vec![1.0; n_sectors],
+                sector_fill_ratio: vec![1.0; n_sectors],
+                ..GoodsOutcome::default()
+            },
             labor_last: LaborOutcome::default(),
             credit_last: CreditOutcome::default(),
+            interbank_last: InterbankOutcome::default(),
+            interbank_exposures: Vec::new(),
+            previous_balance_sheet: SectorBalanceSheet::default(),
+            consistency_last: SfcOutcome::default(),
+            national_accounts_last: NationalAccounts::default(),
+            potential_gdp: 0.0,
+            effective_tax_rate: 0.0,
+            income_gini: 0.0,
+            gs_tax_revenue: 0.0,
+            average_marginal_tax_rate: 0.0,
+            r_gov: 0.0,
+            debt_service: 0.0,
+            debt_target: 0.0,
+            fiscal_closure_adjustment: 0.0,
+            rest_of_world_claims: 0.0,
+            bequest_pool: 0.0,
+            bequest_accrued_this_period: 0.0,
+            total_bequests: 0.0,
+            bequest_top_decile_share: 0.0,
+            productivity_grid,
+            productivity_transition,
+            egm_asset_grid: Vec::new(),
+            egm_consumption_policy: Vec::new(),
             config,
             rng,
             records: Vec::new(),
@@ -100,6 +284,7 @@ impl EconomyState {
             n_banks,
         };
         state.initial_employment();
+        state.previous_balance_sheet = accounting::balance_sheet(&state);
         state
     }
 
@@ -113,7 +298,8 @@ impl EconomyState {
         let cash_ln = LogNormal::new((10_000.0_f64).ln(), 0.8).unwrap();
 
         for i in 0..n {
-            let sector = cfg.sectors[i % cfg.sectors.len()].clone();
+            let sector_index = i % cfg.sectors.len();
+            let sector = cfg.sectors[sector_index].clone();
             let employees: u32 = rng.gen_range(1..50);
             let wage_rate = wage_ln.sample(rng);
             let turnover = turnover_ln.sample(rng);
@@ -122,6 +308,7 @@ impl EconomyState {
 
             firms.push(FirmData::new(
                 sector,
+                sector_index,
                 employees,
                 wage_rate,
                 turnover,
@@ -133,7 +320,12 @@ impl EconomyState {
         firms
     }
 
-    fn create_households(n: usize, cfg: &Config, rng: &mut StdRng) -> Vec<HouseholdData> {
+    fn create_households(
+        n: usize,
+        cfg: &Config,
+        productivity_grid: &[f64],
+        rng: &mut StdRng,
+    ) -> Vec<HouseholdData> {
         let mut households = Vec::with_capacity(n);
         let income_ln =
             LogNormal::new(cfg.income_mean.ln(), cfg.income_std / cfg.income_mean).unwrap();
@@ -143,8 +335,15 @@ impl EconomyState {
             let income = income_ln.sample(rng) / 4.0; // quarterly
             let wealth_pareto = Pareto::new(1.0, cfg.wealth_shape).unwrap();
             let wealth = (wealth_pareto.sample(rng) - 1.0) * income; // pareto gives >= 1
-            let mpc = mpc_normal.sample(rng).clamp(0.1, 0.99);
-            households.push(HouseholdData::new(income, wealth.max(0.0), mpc));
+            let initial_mpc = mpc_normal.sample(rng).clamp(cfg.mpc_min, 0.99);
+            let productivity_state = rng.gen_range(0..productivity_grid.len());
+            households.push(HouseholdData::new(
+                income,
+                wealth.max(0.0),
+                initial_mpc,
+                income.max(1.0),
+                productivity_state,
+            ));
         }
         households
     }
@@ -177,10 +376,62 @@ impl EconomyState {
         }
     }
 
+    /// Redistribute the accumulated bequest pool across surviving households
+    /// and drain it. Weights blend a uniform share with a share proportional
+    /// to existing wealth, controlled by `config.zeta_bq` (0 = uniform, 1 =
+    /// fully proportional to wealth). Returns `(total_distributed,
+    /// top_decile_share)`, the latter being the fraction of the pool
+    /// received by the wealthiest decile of recipients.
+    fn distribute_bequests(&mut self) -> (f64, f64) {
+        let pool = self.bequest_pool;
+        self.bequest_pool = 0.0;
+        if pool <= 0.0 || self.households.is_empty() {
+            return (0.0, 0.0);
+        }
+
+        let zeta_bq = self.config.zeta_bq;
+        let n = self.households.len() as f64;
+        let uniform_share = 1.0 / n;
+        let total_wealth: f64 = self.households.iter().map(|h| h.wealth.max(0.0)).sum();
+
+        let received: Vec<f64> = self
+            .households
+            .iter()
+            .map(|hh| {
+                let wealth_share = if total_wealth > 0.0 {
+                    hh.wealth.max(0.0) / total_wealth
+                } else {
+                    uniform_share
+                };
+                let weight = (1.0 - zeta_bq) * uniform_share + zeta_bq * wealth_share;
+                pool * weight
+            })
+            .collect();
+
+        for (hh, amount) in self.households.iter_mut().zip(received.iter()) {
+            hh.wealth += amount;
+        }
+
+        let mut sorted = received.clone();
+        sorted.sort_by(|a, b| b.partial_cmp(a).unwrap());
+        let top_n = ((sorted.len() as f64 * 0.1).ceil() as usize).max(1);
+        let top_decile_share = sorted.iter().take(top_n).sum::<f64>() / pool;
+
+        (pool, top_decile_share)
+    }
+
     // ─── Per-period step helpers ─────────────────────────────────────────────
 
-    /// Run the pre-agent-step sequence (government begin → credit market).
+    /// Run the pre-agent-step sequence (government begin → credit market →
+    /// interbank market).
     pub fn run_pre_step(&mut self) {
+        // 0. Distribute last period's bequest pool (residual net worth of
+        // firms that went bankrupt last period) across surviving households.
+        let (total_bequests, bequest_top_decile_share) = self.distribute_bequests();
+        self.total_bequests = total_bequests;
+        self.bequest_top_decile_share = bequest_top_decile_share;
+        self.bequest_accrued_this_period = 0.0;
+
         // 1. Government begins period
         self.government.begin_period();
 
@@ -192,6 +443,32 @@ impl EconomyState {
             self.config.lower_bound,
         );
 
+        // 2b. Re-solve the household EGM consumption-savings policy for this
+        // period's policy rate. Reuses the shared Tauchen productivity chain
+        // as the income Markov process (scaled by the average quarterly
+        // wage) rather than fitting a second income discretization.
+        let mean_wage = (self.config.income_mean / 4.0).max(1.0);
+        let income_states: Vec<f64> = self
+            .productivity_grid
+            .iter()
+            .map(|&y| mean_wage * y)
+            .collect();
+        let borrowing_limit = -self.config.borrowing_limit_ratio * mean_wage;
+        let (egm_asset_grid, egm_consumption_policy) = solve_egm_policy(
+            &income_states,
+            &self.productivity_transition,
+            self.config.crra_sigma,
+            self.config.discount_factor,
+            self.central_bank.policy_rate,
+            borrowing_limit,
+            self.config.egm_asset_max,
+            self.config.egm_grid_size,
+            self.config.egm_max_iterations,
+            self.config.egm_tolerance,
+        );
+        self.egm_asset_grid = egm_asset_grid;
+        self.egm_consumption_policy = egm_consumption_policy;
+
         // 3. Banks update lending rates based on new policy rate
         let policy_rate = self.central_bank.policy_rate;
         let base_markup = self.config.base_interest_markup;
@@ -202,6 +479,85 @@ impl EconomyState {
 
         // 4. Credit market clears
         clear_credit_market(self);
+        // Foreign-financed lending this period becomes a permanent claim
+        // the rest-of-world sector holds on firms (see
+        // `rest_of_world_claims` and the `new_lending` row in
+        // `accounting::build_flow_matrix`).
+        self.rest_of_world_claims += self.credit_last.net_foreign_inflow;
+
+        // 5. Interbank market clears and resolves any contagion cascade
+        // triggered by the firm defaults just processed above.
+        self.interbank_last = clear_interbank_market(self);
+    }
+
+    /// Batch-parallel equivalent of every `FirmAgent::step` this period:
+    /// each firm's independent phase (`step_firm_independent`) reads only
+    /// shared, already-computed inputs (its own IO row, lagged per-sector
+    /// fill ratios/prices) and writes only to its own `FirmData`, so the
+    /// whole vector can run under a rayon `par_iter_mut` instead of one
+    /// krabmaga-scheduled agent at a time. Triggered by the `FirmAgent` with
+    /// id 0 when `Config::parallel_agent_stepping` is set (see
+    /// `FirmAgent::step`).
+    #[cfg(feature = "parallel")]
+    pub fn step_firms_parallel(&mut self) {
+        use rayon::prelude::*;
+
+        let io = self.config.io_coefficients.clone();
+        let sector_fill_ratio = self.goods_last.sector_fill_ratio.clone();
+        let sector_prices = self.goods_last.sector_price.clone();
+        let inv_ratio = self.config.inventory_target_ratio;
+        let cap_util = self.config.capacity_utilization_target;
+        let delta = self.config.capital_depreciation_rate;
+        let investment_sensitivity = self.config.investment_sensitivity;
+
+        self.firms.par_iter_mut().for_each(|firm| {
+            let io_row = &io[firm.sector_index];
+            step_firm_independent(
+                firm,
+                io_row,
+                &sector_fill_ratio,
+                &sector_prices,
+                inv_ratio,
+                cap_util,
+                delta,
+                investment_sensitivity,
+            );
+        });
+    }
+
+    /// Batch-parallel equivalent of every `HouseholdAgent::step` this
+    /// period. `HouseholdData::step`'s only shared mutable input is the
+    /// idiosyncratic productivity draw, so every draw is pre-generated
+    /// serially from the shared RNG before the parallel sweep — this keeps
+    /// the batch pass exactly as reproducible as the serial path for a
+    /// given seed. Triggered by the `HouseholdAgent` with id 0 when
+    /// `Config::parallel_agent_stepping` is set (see `HouseholdAgent::step`).
+    #[cfg(feature = "parallel")]
+    pub fn step_households_parallel(&mut self) {
+        use rayon::prelude::*;
+
+        let r = self.central_bank.policy_rate;
+        let borrowing_limit_ratio = self.config.borrowing_limit_ratio;
+        let productivity_grid = self.productivity_grid.clone();
+        let productivity_transition = self.productivity_transition.clone();
+        let egm_asset_grid = self.egm_asset_grid.clone();
+        let egm_consumption_policy = self.egm_consumption_policy.clone();
+        let draws: Vec<f64> = (0..self.households.len()).map(|_| self.rng.gen()).collect();
+
+        self.households
+            .par_iter_mut()
+            .zip(draws.par_iter())
+            .for_each(|(hh, &draw)| {
+                hh.step(
+                    r,
+                    borrowing_limit_ratio,
+                    &productivity_grid,
+                    &productivity_transition,
+                    draw,
+                    &egm_asset_grid,
+                    &egm_consumption_policy,
+                );
+            });
     }
 
     /// Run the post-agent-step sequence (government spending → CB observes).
@@ -222,40 +578,189 @@ impl EconomyState {
         // 7. Goods market clears
         clear_goods_market(self);
 
+        // 7b. Expenditure-side national accounts (GDP = C + I + G, with a
+        // real-vs-nominal split and per-sector demand satisfaction; see
+        // `accounting::national_accounts`). `potential_gdp` is an
+        // exponential moving average of realised real GDP, tracking trend
+        // output for the central bank's Taylor rule output-gap term below.
+        self.national_accounts_last = accounting::national_accounts(self);
+        let real_gdp = self.national_accounts_last.real_gdp;
+        self.potential_gdp = if self.potential_gdp > 0.0 {
+            self.config.potential_gdp_smoothing * self.potential_gdp
+                + (1.0 - self.config.potential_gdp_smoothing) * real_gdp
+        } else {
+            real_gdp
+        };
+
         // 8. Tax collection
         let corp_rate = self.config.tax_rate_corporate;
-        let income_rate = self.config.tax_rate_income;
+        let capital_phi = self.config.capital_tax_phi;
+        let capital_p = self.config.capital_tax_curvature;
+        let capital_s = self.config.capital_tax_scale;
+        let mut firm_tax_total = 0.0;
+        let mut gs_tax_total = 0.0;
+        let mut mtr_sum = 0.0;
+        let mut mtr_count = 0usize;
         for firm in self.firms.iter_mut() {
             if firm.profit > 0.0 && !firm.bankrupt {
                 let tax = (firm.profit * corp_rate).max(0.0);
-                self.government.tax_revenue += tax;
-                firm.cash -= tax;
+                let capital_tax =
+                    GovernmentData::gs_tax_liability(firm.profit, capital_phi, capital_p, capital_s);
+                self.government.tax_revenue += tax + capital_tax;
+                firm.cash -= tax + capital_tax;
+                firm.tax_paid = tax + capital_tax;
+                firm_tax_total += tax + capital_tax;
+                gs_tax_total += capital_tax;
+                mtr_sum += GovernmentData::gs_marginal_tax_rate(firm.profit, capital_phi, capital_p, capital_s);
+                mtr_count += 1;
+            } else {
+                firm.tax_paid = 0.0;
             }
         }
+        let income_tax_schedule = self.config.income_tax_schedule.clone();
+        let income_tax_multiplier = self.government.income_tax_multiplier;
+        let labor_phi = self.config.labor_tax_phi;
+        let labor_p = self.config.labor_tax_curvature;
+        let labor_s = self.config.labor_tax_scale;
+        let mut household_tax_total = 0.0;
+        let mut pretax_income_total = 0.0;
+        let mut net_incomes: Vec<f64> = Vec::with_capacity(self.households.len());
         for hh in self.households.iter_mut() {
-            if hh.income > 0.0 {
-                let tax = (hh.income * income_rate).max(0.0);
-                self.government.tax_revenue += tax;
-                hh.wealth -= tax;
+            let (schedule_tax_base, schedule_marginal_rate) =
+                GovernmentData::income_tax_liability(hh.income, &income_tax_schedule);
+            let schedule_tax = schedule_tax_base * income_tax_multiplier;
+            let labor_tax =
+                GovernmentData::gs_tax_liability(hh.wage_income, labor_phi, labor_p, labor_s);
+            let capital_tax = GovernmentData::gs_tax_liability(
+                hh.capital_income.max(0.0),
+                capital_phi,
+                capital_p,
+                capital_s,
+            );
+            let tax = schedule_tax + labor_tax + capital_tax;
+            self.government.tax_revenue += tax;
+            hh.wealth -= tax;
+            household_tax_total += tax;
+            gs_tax_total += labor_tax + capital_tax;
+            pretax_income_total += hh.income.max(0.0);
+            net_incomes.push((hh.income - tax).max(0.0));
+            let labor_marginal_rate = if hh.wage_income > 0.0 {
+                GovernmentData::gs_marginal_tax_rate(hh.wage_income, labor_phi, labor_p, labor_s)
+            } else {
+                0.0
+            };
+            hh.marginal_tax_rate = schedule_marginal_rate * income_tax_multiplier + labor_marginal_rate;
+            if hh.wage_income > 0.0 {
+                mtr_sum += labor_marginal_rate;
+                mtr_count += 1;
             }
         }
+        let effective_tax_rate = if pretax_income_total > 0.0 {
+            household_tax_total / pretax_income_total
+        } else {
+            0.0
+        };
+        let income_gini = gini(&mut net_incomes);
+        self.effective_tax_rate = effective_tax_rate;
+        self.income_gini = income_gini;
+        self.gs_tax_revenue = gs_tax_total;
+        self.average_marginal_tax_rate = if mtr_count > 0 {
+            mtr_sum / mtr_count as f64
+        } else {
+            0.0
+        };
 
-        // 9. Government step (fiscal rule) + end period
-        self.government
-            .apply_fiscal_rule(self.config.deficit_target, self.config.deficit_adjustment_speed);
-        self.government.end_period();
+        // 8b. Cash-buffer bankruptcy check: a firm whose cash balance falls
+        // below its required buffer (a number of months of wage,
+        // intermediate-input, and tax outflows that shrinks as turnover
+        // grows; the `buffer_months_*` parameters are the same for every
+        // sector) exits. Replaces the old equity-to-capital ratio trigger.
+        let buffer_months_min = self.config.buffer_months_min;
+        let buffer_months_max = self.config.buffer_months_max;
+        let buffer_size_scale = self.config.buffer_size_scale;
+        for firm in self.firms.iter_mut() {
+            if firm.bankrupt {
+                continue;
+            }
+            let required =
+                firm.required_cash_buffer(buffer_months_min, buffer_months_max, buffer_size_scale);
+            if firm.cash < required {
+                firm.bankrupt = true;
+            }
+        }
 
-        // 10. Central bank observes inflation and output gap
+        // 9. Government step (fiscal rule) + end period
+        let (debt_target, fiscal_closure_adjustment) = self.government.apply_fiscal_rule(
+            self.config.fiscal_rule_mode,
+            self.config.deficit_target,
+            self.config.deficit_adjustment_speed,
+            self.config.debt_target_ratio,
+            self.config.debt_stabilization_speed,
+            self.current_period,
+            self.config.debt_ratio_ss,
+            self.config.fiscal_closure_gain,
+            self.config.fiscal_closure_start_period,
+            self.config.fiscal_closure_budget_balance,
+        );
+        self.debt_target = debt_target;
+        self.fiscal_closure_adjustment = fiscal_closure_adjustment;
+        let debt_ratio_for_spread = if self.government.gdp_estimate > 0.0 {
+            (self.government.debt / self.government.gdp_estimate).max(0.0)
+        } else {
+            0.0
+        };
+        let r_gov = self.central_bank.policy_rate
+            + self.config.sovereign_spread_sensitivity * debt_ratio_for_spread;
+        self.r_gov = r_gov;
+        // Households' `capital_income` (see `HouseholdData::receive_income`)
+        // has no modeled payer other than the government — fund it here so
+        // it has a real counterparty instead of accruing to wealth for free
+        // (see the balanced `household_capital_income` row in
+        // `accounting::build_flow_matrix`).
+        let household_interest_paid: f64 =
+            self.households.iter().map(|h| h.capital_income).sum();
+        self.debt_service = self
+            .government
+            .end_period(r_gov, household_interest_paid);
+
+        // 10. Central bank observes inflation and the measured output gap
+        // (real GDP relative to its trend, from the national accounts
+        // computed in step 7b) instead of the previous hardcoded 0.0.
+        let output_gap = if self.potential_gdp > 0.0 {
+            (self.national_accounts_last.real_gdp - self.potential_gdp) / self.potential_gdp
+        } else {
+            0.0
+        };
         self.central_bank
-            .update_observations(self.goods_last.inflation, 0.0);
+            .update_observations(self.goods_last.inflation, output_gap);
 
-        // 11. Banks do their full period step (update income and capital)
+        // 11. Banks do their full period step (amortize loan book, update
+        // income and capital), retiring the matching liability on each
+        // borrower's own books as loans are repaid.
         let policy_rate = self.central_bank.policy_rate;
         let base_markup = self.config.base_interest_markup;
         let risk = self.config.risk_premium_sensitivity;
+        let mut loan_repayment_total = 0.0;
         for bank in self.banks.iter_mut() {
-            bank.step(policy_rate, base_markup, risk);
+            for (borrower_id, amount) in bank.step(policy_rate, base_markup, risk) {
+                if let Some(firm) = self.firms.get_mut(borrower_id) {
+                    firm.debt = (firm.debt - amount).max(0.0);
+                    firm.cash -= amount;
+                }
+                loan_repayment_total += amount;
+            }
         }
+
+        // 12. Stock-flow consistency audit (assets/liabilities and flows
+        // should reconcile; see `accounting::check_consistency`).
+        let sfc_tolerance = self.config.sfc_tolerance;
+        self.consistency_last = accounting::check_consistency(
+            self,
+            household_tax_total,
+            firm_tax_total,
+            loan_repayment_total,
+            sfc_tolerance,
+        );
     }
 
     /// Record aggregate statistics for the completed period.
@@ -274,10 +779,75 @@ impl EconomyState {
             total_lending: self.credit_last.total_lending,
             firm_bankruptcies: bankruptcies,
             total_employment: self.labor_last.total_employed,
+            effective_tax_rate: self.effective_tax_rate,
+            income_gini: self.income_gini,
+            bank_credit_stock: self.banks.iter().map(|b| b.loans).sum(),
+            bank_failures: self.interbank_last.bank_failures,
+            interbank_contagion_losses: self.interbank_last.contagion_losses,
+            r_gov: self.r_gov,
+            debt_service: self.debt_service,
+            debt_to_gdp: if self.government.gdp_estimate > 0.0 {
+                self.government.debt / self.government.gdp_estimate
+            } else {
+                0.0
+            },
+            average_mpc: if self.households.is_empty() {
+                0.0
+            } else {
+                self.households.iter().map(|h| h.mpc).sum::<f64>() / self.households.len() as f64
+            },
+            total_investment: self
+                .firms
+                .iter()
+                .filter(|f| !f.bankrupt)
+                .map(|f| f.investment)
+                .sum(),
+            sfc_max_residual: self.consistency_last.max_residual,
+            total_household_wealth: self.households.iter().map(|h| h.wealth).sum(),
+            wealth_gini: gini(
+                &mut self
+                    .households
+                    .iter()
+                    .map(|h| h.wealth.max(0.0))
+                    .collect::<Vec<f64>>(),
+            ),
+            gs_tax_revenue: self.gs_tax_revenue,
+            average_marginal_tax_rate: self.average_marginal_tax_rate,
+            net_foreign_inflow: self.credit_last.net_foreign_inflow,
+            debt_target: self.debt_target,
+            fiscal_closure_adjustment: self.fiscal_closure_adjustment,
+            total_bequests: self.total_bequests,
+            bequest_top_decile_share: self.bequest_top_decile_share,
+            nominal_gdp: self.national_accounts_last.nominal_gdp,
+            real_gdp: self.national_accounts_last.real_gdp,
+            price_index: self.national_accounts_last.price_index,
+            output_gap: self.central_bank.output_gap,
         });
     }
 }
 
+/// Gini coefficient of a (non-negative) value distribution.
+///
+/// Sorts `values` in place and applies the standard rank-weighted formula;
+/// returns 0 when there is nothing to compare (empty input or zero total).
+fn gini(values: &mut [f64]) -> f64 {
+    let n = values.len();
+    if n == 0 {
+        return 0.0;
+    }
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let total: f64 = values.iter().sum();
+    if total <= 0.0 {
+        return 0.0;
+    }
+    let weighted: f64 = values
+        .iter()
+        .enumerate()
+        .map(|(i, &x)| (2.0 * (i as f64 + 1.0) - n as f64 - 1.0) * x)
+        .sum();
+    weighted / (n as f64 * total)
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // krabmaga State implementation
 // ─────────────────────────────────────────────────────────────────────────────
@@ -327,19 +897,50 @@ impl State for EconomyState {
         // Re-initialise from scratch (used by simulate! macro for repetitions)
         let mut rng = StdRng::seed_from_u64(42);
         self.firms = Self::create_firms(self.n_firms, &self.config.clone(), &mut rng);
-        self.households =
-            Self::create_households(self.n_households, &self.config.clone(), &mut rng);
+        self.households = Self::create_households(
+            self.n_households,
+            &self.config.clone(),
+            &self.productivity_grid.clone(),
+            &mut rng,
+        );
         self.banks = Self::create_banks(self.n_banks, &mut rng);
         self.central_bank = CentralBankData::new(self.config.inflation_target);
         self.government = GovernmentData::new();
+        let n_sectors = self.config.sectors.len();
         self.goods_average_price = 1.0;
-        self.goods_last = GoodsOutcome::default();
+        self.base_average_price = 1.0;
+        self.goods_last = GoodsOutcome {
+            sector_price: vec![1.0; n_sectors],
+            sector_fill_ratio: vec![1.0; n_sectors],
+            ..GoodsOutcome::default()
+        };
         self.labor_last = LaborOutcome::default();
         self.credit_last = CreditOutcome::default();
+        self.interbank_last = InterbankOutcome::default();
+        self.interbank_exposures = Vec::new();
+        self.consistency_last = SfcOutcome::default();
+        self.national_accounts_last = NationalAccounts::default();
+        self.potential_gdp = 0.0;
+        self.effective_tax_rate = 0.0;
+        self.income_gini = 0.0;
+        self.gs_tax_revenue = 0.0;
+        self.average_marginal_tax_rate = 0.0;
+        self.r_gov = 0.0;
+        self.debt_service = 0.0;
+        self.debt_target = 0.0;
+        self.fiscal_closure_adjustment = 0.0;
+        self.rest_of_world_claims = 0.0;
+        self.bequest_pool = 0.0;
+        self.bequest_accrued_this_period = 0.0;
+        self.total_bequests = 0.0;
+        self.bequest_top_decile_share = 0.0;
+        self.egm_asset_grid = Vec::new();
+        self.egm_consumption_policy = Vec::new();
         self.rng = rng;
         self.records.clear();
         self.current_period = 0;
         self.initial_employment();
+        self.previous_balance_sheet = accounting::balance_sheet(self);
     }
 
     /// Run the pre-agent step (government, CB, banks, credit market).